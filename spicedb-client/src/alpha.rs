@@ -0,0 +1,119 @@
+//! Typed wrappers for the `v1alpha1` APIs, which may change or be removed in
+//! any SpiceDB release.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use spicedb_grpc::authzed::api::v1::ZedToken;
+use spicedb_grpc::authzed::api::v1alpha1::{PermissionUpdate, WatchResourcesResponse};
+use tokio_stream::Stream;
+use tonic::Status;
+
+use crate::result::Result;
+
+pub(crate) type WatchResourcesResponses =
+    Pin<Box<dyn Stream<Item = Result<WatchResourcesResponse, Status>> + Send>>;
+
+/// Stream of the permission updates returned by
+/// [`SpicedbClient::watch_resources`](crate::SpicedbClient::watch_resources).
+///
+/// Updates are sent in batches. [`changes_through`](Self::changes_through)
+/// is the revision up to which all the updates have been yielded, and can be
+/// set as the `optional_start_cursor` of a new request to resume watching.
+pub struct PermissionUpdates {
+    responses: WatchResourcesResponses,
+    updates: VecDeque<PermissionUpdate>,
+    /// Revision of the batch currently being yielded.
+    next_changes_through: Option<ZedToken>,
+    changes_through: Option<ZedToken>,
+}
+
+impl PermissionUpdates {
+    pub(crate) fn new(responses: WatchResourcesResponses) -> Self {
+        Self {
+            responses,
+            updates: VecDeque::new(),
+            next_changes_through: None,
+            changes_through: None,
+        }
+    }
+
+    /// Revision up to which all the updates have been yielded.
+    pub fn changes_through(&self) -> Option<&ZedToken> {
+        self.changes_through.as_ref()
+    }
+}
+
+impl Stream for PermissionUpdates {
+    type Item = Result<PermissionUpdate>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(update) = this.updates.pop_front() {
+                return Poll::Ready(Some(Ok(update)));
+            }
+            if let Some(changes_through) = this.next_changes_through.take() {
+                this.changes_through = Some(changes_through);
+            }
+
+            match ready!(this.responses.as_mut().poll_next(cx)) {
+                Some(Ok(response)) => {
+                    this.updates.extend(response.updates);
+                    this.next_changes_through = response.changes_through;
+                }
+                Some(Err(status)) => return Poll::Ready(Some(Err(status.into()))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PermissionUpdates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PermissionUpdates")
+            .field("changes_through", &self.changes_through)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tokio::test;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    fn response(relations: &[&str], token: &str) -> Result<WatchResourcesResponse, Status> {
+        Ok(WatchResourcesResponse {
+            updates: relations
+                .iter()
+                .map(|relation| PermissionUpdate {
+                    relation: relation.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+            changes_through: Some(ZedToken {
+                token: token.to_string(),
+            }),
+        })
+    }
+
+    #[test]
+    pub async fn test_permission_updates() {
+        let responses = vec![response(&["view", "edit"], "1"), response(&["view"], "2")];
+        let mut updates = PermissionUpdates::new(Box::pin(tokio_stream::iter(responses)));
+
+        assert_eq!(updates.next().await.unwrap().unwrap().relation, "view");
+        assert_eq!(updates.changes_through(), None);
+        assert_eq!(updates.next().await.unwrap().unwrap().relation, "edit");
+        assert_eq!(updates.next().await.unwrap().unwrap().relation, "view");
+        assert_eq!(updates.changes_through().unwrap().token, "1");
+        assert!(updates.next().await.is_none());
+        assert_eq!(updates.changes_through().unwrap().token, "2");
+    }
+}
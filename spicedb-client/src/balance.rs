@@ -0,0 +1,211 @@
+//! Client-side load balancing across several SpiceDB endpoints.
+//!
+//! Requests are spread over the endpoints by tonic's balanced [`Channel`].
+//! Its connections are established lazily, so it keeps sending requests to
+//! endpoints that are down. A background task therefore probes every endpoint
+//! at the health check interval and only keeps the healthy ones in the
+//! channel, re-resolving DNS as configured.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use http::Uri;
+use spicedb_grpc::authzed::api::v1::schema_service_client::SchemaServiceClient;
+use spicedb_grpc::authzed::api::v1::ReadSchemaRequest;
+use tokio::net::lookup_host;
+use tokio::sync::mpsc::Sender;
+use tokio::task::JoinSet;
+use tokio::time::{sleep, timeout, Instant};
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Request, Status};
+use tower::discover::Change;
+
+use crate::builder::SpicedbClientBuilder;
+use crate::result::Result;
+
+/// Capacity of the queue of changes sent to the balanced channel.
+const CHANGES_CAPACITY: usize = 1024;
+
+pub(crate) struct Balancer {
+    builder: SpicedbClientBuilder,
+    urls: Vec<Uri>,
+    /// Endpoints each URL currently resolves to, in the same order as `urls`.
+    resolved: Vec<Vec<Endpoint>>,
+    last_resolved: Option<Instant>,
+    /// Endpoints currently receiving requests.
+    active: HashSet<Uri>,
+    /// Connection to each endpoint used for health checks, kept between
+    /// checks.
+    probes: HashMap<Uri, Channel>,
+    changes: Sender<Change<Uri, Endpoint>>,
+}
+
+impl Balancer {
+    pub(crate) fn new(builder: &SpicedbClientBuilder) -> Result<(Channel, Self)> {
+        let urls = builder
+            .urls
+            .iter()
+            .map(|url| Uri::from_maybe_shared(url.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let resolved = builder
+            .urls
+            .iter()
+            .map(|url| Ok(vec![builder.endpoint(url.clone())?]))
+            .collect::<Result<_>>()?;
+
+        let (channel, changes) = Channel::balance_channel(CHANGES_CAPACITY);
+        let balancer = Self {
+            builder: builder.clone(),
+            urls,
+            resolved,
+            last_resolved: None,
+            active: HashSet::new(),
+            probes: HashMap::new(),
+            changes,
+        };
+        Ok((channel, balancer))
+    }
+
+    /// Send requests to every endpoint, without checking that it is reachable.
+    pub(crate) fn insert_all(&mut self) {
+        for endpoint in self.resolved.iter().flatten() {
+            let uri = endpoint.uri().clone();
+            if self.active.insert(uri.clone()) {
+                let _ = self.changes.try_send(Change::Insert(uri, endpoint.clone()));
+            }
+        }
+    }
+
+    /// Resolve the endpoints again if due, and update the channel to only
+    /// send requests to the healthy ones.
+    ///
+    /// If no endpoint is healthy, requests are sent to all of them and the
+    /// last health check error is returned.
+    pub(crate) async fn refresh(&mut self) -> Result<()> {
+        if let Some(interval) = self.builder.dns_refresh_interval {
+            if self
+                .last_resolved
+                .is_none_or(|last_resolved| last_resolved.elapsed() >= interval)
+            {
+                self.resolve().await;
+                self.last_resolved = Some(Instant::now());
+            }
+        }
+
+        let endpoints: HashMap<Uri, Endpoint> = self
+            .resolved
+            .iter()
+            .flatten()
+            .map(|endpoint| (endpoint.uri().clone(), endpoint.clone()))
+            .collect();
+
+        self.probes.retain(|uri, _| endpoints.contains_key(uri));
+        let mut probes = JoinSet::new();
+        for (uri, endpoint) in &endpoints {
+            let channel = self
+                .probes
+                .entry(uri.clone())
+                .or_insert_with(|| endpoint.clone().connect_lazy())
+                .clone();
+            let uri = uri.clone();
+            let timeout = self.builder.health_check_timeout;
+            probes.spawn(async move { (uri, probe(channel, timeout).await) });
+        }
+
+        let mut healthy = HashSet::new();
+        let mut last_error = None;
+        while let Some(probe) = probes.join_next().await {
+            match probe {
+                Ok((uri, Ok(_))) => {
+                    healthy.insert(uri);
+                }
+                Ok((_, Err(err))) => last_error = Some(err),
+                Err(_) => {}
+            }
+        }
+
+        let result = match last_error {
+            Some(err) if healthy.is_empty() => {
+                healthy = endpoints.keys().cloned().collect();
+                Err(err)
+            }
+            _ => Ok(()),
+        };
+
+        for uri in self.active.difference(&healthy) {
+            let _ = self.changes.send(Change::Remove(uri.clone())).await;
+        }
+        for uri in healthy.difference(&self.active) {
+            let change = Change::Insert(uri.clone(), endpoints[uri].clone());
+            let _ = self.changes.send(change).await;
+        }
+        self.active = healthy;
+
+        result
+    }
+
+    /// Refresh the endpoints in the background until the channel is dropped.
+    pub(crate) fn spawn(mut self, refresh_now: bool) {
+        tokio::spawn(async move {
+            if !refresh_now {
+                sleep(self.builder.health_check_interval).await;
+            }
+            while !self.changes.is_closed() {
+                let _ = self.refresh().await;
+                sleep(self.builder.health_check_interval).await;
+            }
+        });
+    }
+
+    /// Resolve the host of every URL. URLs that fail to resolve keep their
+    /// previous endpoints.
+    async fn resolve(&mut self) {
+        for (url, resolved) in self.urls.iter().zip(&mut self.resolved) {
+            let Some(host) = url.host() else {
+                continue;
+            };
+            let host = host.trim_start_matches('[').trim_end_matches(']');
+            let port = url.port_u16().unwrap_or(match url.scheme_str() {
+                Some("https") => 443,
+                _ => 80,
+            });
+            let Ok(addrs) = lookup_host((host, port)).await else {
+                continue;
+            };
+
+            let endpoints: Vec<_> = addrs
+                .filter_map(|addr| self.builder.resolved_endpoint(url, addr).ok())
+                .collect();
+            if !endpoints.is_empty() {
+                *resolved = endpoints;
+            }
+        }
+    }
+}
+
+/// Check that the server behind `channel` answers a schema read within
+/// `timeout`.
+///
+/// The request is not authenticated: any reply, such as `UNAUTHENTICATED` or
+/// `NOT_FOUND` without a schema, shows that the server is up.
+async fn probe(channel: Channel, timeout_after: Duration) -> Result<()> {
+    let mut request = Request::new(ReadSchemaRequest {});
+    request.set_timeout(timeout_after);
+    let response = timeout(
+        timeout_after,
+        SchemaServiceClient::new(channel).read_schema(request),
+    )
+    .await
+    .map_err(|_| Status::deadline_exceeded("health check timed out"))?;
+    match response {
+        Err(status)
+            if matches!(
+                status.code(),
+                Code::Unavailable | Code::DeadlineExceeded | Code::Unknown
+            ) =>
+        {
+            Err(status.into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,465 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::Uri;
+#[cfg(feature = "tls")]
+use tonic::transport::ClientTlsConfig;
+use tonic::transport::{Channel, Endpoint};
+
+use crate::balance::Balancer;
+
+use crate::credentials::{Credentials, CredentialsProvider, StaticKey};
+use crate::result::Result;
+use crate::retry::RetryPolicy;
+#[cfg(unix)]
+use crate::unix::{unix_socket_path, UnixConnector, UNIX_ENDPOINT_URL};
+use crate::SpicedbClient;
+
+/// Configure and connect a [`SpicedbClient`].
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use spicedb_client::builder::SpicedbClientBuilder;
+/// #
+/// # async fn create_client() {
+/// let client = SpicedbClientBuilder::new("http://localhost:50051")
+///     .preshared_key("spicedb")
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(10))
+///     .http2_keep_alive_interval(Duration::from_secs(30))
+///     .connect_lazy()
+///     .unwrap();
+/// # }
+/// ```
+///
+/// Requests can be balanced across several replicas. Endpoints that fail
+/// health checks are taken out of rotation until they recover:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use spicedb_client::builder::SpicedbClientBuilder;
+/// #
+/// # async fn create_client() {
+/// let client = SpicedbClientBuilder::new("http://spicedb-0:50051")
+///     .add_endpoint("http://spicedb-1:50051")
+///     .add_endpoint("http://spicedb-2:50051")
+///     .preshared_key("spicedb")
+///     .connect()
+///     .await
+///     .unwrap();
+///
+/// // Balance across every address a headless service resolves to.
+/// let client = SpicedbClientBuilder::new("http://spicedb.default.svc:50051")
+///     .dns_refresh_interval(Duration::from_secs(30))
+///     .preshared_key("spicedb")
+///     .connect()
+///     .await
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpicedbClientBuilder {
+    pub(crate) urls: Vec<Bytes>,
+    credentials: Option<Credentials>,
+    retry_policy: RetryPolicy,
+    pub(crate) dns_refresh_interval: Option<Duration>,
+    pub(crate) health_check_interval: Duration,
+    pub(crate) health_check_timeout: Duration,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    http2_keep_alive_interval: Option<Duration>,
+    keep_alive_timeout: Option<Duration>,
+    keep_alive_while_idle: Option<bool>,
+    tcp_keepalive: Option<Duration>,
+    tcp_nodelay: Option<bool>,
+    concurrency_limit: Option<usize>,
+    max_decoding_message_size: Option<usize>,
+    max_encoding_message_size: Option<usize>,
+    user_agent: Option<String>,
+    #[cfg(feature = "tls")]
+    tls_config: Option<ClientTlsConfig>,
+}
+
+const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl SpicedbClientBuilder {
+    /// Create a new builder for the SpiceDB server at `url`.
+    ///
+    /// On Unix, `unix:///path/to/socket` URLs connect over a Unix domain
+    /// socket. They cannot be balanced with other endpoints.
+    pub fn new(url: impl Into<Bytes>) -> Self {
+        Self {
+            urls: vec![url.into()],
+            credentials: None,
+            retry_policy: RetryPolicy::default(),
+            dns_refresh_interval: None,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            health_check_timeout: DEFAULT_HEALTH_CHECK_TIMEOUT,
+            connect_timeout: None,
+            timeout: None,
+            http2_keep_alive_interval: None,
+            keep_alive_timeout: None,
+            keep_alive_while_idle: None,
+            tcp_keepalive: None,
+            tcp_nodelay: None,
+            concurrency_limit: None,
+            max_decoding_message_size: None,
+            max_encoding_message_size: None,
+            user_agent: None,
+            #[cfg(feature = "tls")]
+            tls_config: None,
+        }
+    }
+
+    /// Balance requests across another SpiceDB endpoint.
+    ///
+    /// All endpoints must share the same scheme and TLS configuration.
+    pub fn add_endpoint(&mut self, url: impl Into<Bytes>) -> &mut Self {
+        self.urls.push(url.into());
+        self
+    }
+
+    /// Resolve the host of every endpoint and balance requests across all of
+    /// its addresses, resolving again at `interval` to pick up replicas as
+    /// they come and go.
+    ///
+    /// With TLS, certificates are verified against the host in the URL.
+    pub fn dns_refresh_interval(&mut self, interval: Duration) -> &mut Self {
+        self.dns_refresh_interval = Some(interval);
+        self
+    }
+
+    /// Connect to the endpoints by URL without resolving their hosts.
+    pub fn clear_dns_refresh_interval(&mut self) -> &mut Self {
+        self.dns_refresh_interval = None;
+        self
+    }
+
+    /// Interval between health checks of each balanced endpoint, which read
+    /// the schema over a connection kept between checks. Endpoints that fail
+    /// a check stop receiving requests until a later check succeeds.
+    ///
+    /// Only used with several endpoints or DNS resolution. Defaults to 5
+    /// seconds.
+    pub fn health_check_interval(&mut self, interval: Duration) -> &mut Self {
+        self.health_check_interval = interval;
+        self
+    }
+
+    /// Time after which a health check fails, including connecting to the
+    /// endpoint. Defaults to 1 second.
+    pub fn health_check_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.health_check_timeout = timeout;
+        self
+    }
+
+    /// Authenticate every request with a preshared key.
+    pub fn preshared_key(&mut self, preshared_key: impl ToString) -> &mut Self {
+        self.credentials(StaticKey::new(preshared_key))
+    }
+
+    /// Authenticate every request with a token from `provider`.
+    ///
+    /// The provider is consulted before each request, see
+    /// [`credentials`](crate::credentials) for the built-in providers.
+    pub fn credentials(&mut self, provider: impl CredentialsProvider) -> &mut Self {
+        self.credentials = Some(Credentials::new(provider));
+        self
+    }
+
+    /// Send requests without authentication.
+    pub fn clear_credentials(&mut self) -> &mut Self {
+        self.credentials = None;
+        self
+    }
+
+    /// Retry policy for transient failures of idempotent requests.
+    ///
+    /// Defaults to [`RetryPolicy::default`], use [`RetryPolicy::disabled`] to
+    /// never retry.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Timeout for establishing the connection to the server.
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Wait for the connection to be established without a timeout.
+    pub fn clear_connect_timeout(&mut self) -> &mut Self {
+        self.connect_timeout = None;
+        self
+    }
+
+    /// Timeout applied to each request.
+    ///
+    /// For streaming responses the timeout covers receiving the response
+    /// headers, not consuming the whole stream.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests without a timeout.
+    pub fn clear_timeout(&mut self) -> &mut Self {
+        self.timeout = None;
+        self
+    }
+
+    /// Interval between HTTP/2 keepalive pings.
+    pub fn http2_keep_alive_interval(&mut self, interval: Duration) -> &mut Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Disable HTTP/2 keepalive pings.
+    pub fn clear_http2_keep_alive_interval(&mut self) -> &mut Self {
+        self.http2_keep_alive_interval = None;
+        self
+    }
+
+    /// How long to wait for a keepalive ping to be acknowledged before the
+    /// connection is closed.
+    pub fn keep_alive_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Use the default keepalive ping timeout of 20 seconds.
+    pub fn clear_keep_alive_timeout(&mut self) -> &mut Self {
+        self.keep_alive_timeout = None;
+        self
+    }
+
+    /// Send keepalive pings even when there are no active requests.
+    pub fn keep_alive_while_idle(&mut self, enabled: bool) -> &mut Self {
+        self.keep_alive_while_idle = Some(enabled);
+        self
+    }
+
+    /// Only send keepalive pings while there are active requests, the
+    /// default.
+    pub fn clear_keep_alive_while_idle(&mut self) -> &mut Self {
+        self.keep_alive_while_idle = None;
+        self
+    }
+
+    /// Interval between TCP keepalive probes.
+    pub fn tcp_keepalive(&mut self, interval: Duration) -> &mut Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Disable TCP keepalive probes.
+    pub fn clear_tcp_keepalive(&mut self) -> &mut Self {
+        self.tcp_keepalive = None;
+        self
+    }
+
+    /// Set the `TCP_NODELAY` option on the connection.
+    pub fn tcp_nodelay(&mut self, enabled: bool) -> &mut Self {
+        self.tcp_nodelay = Some(enabled);
+        self
+    }
+
+    /// Use the default `TCP_NODELAY` option of tonic, which is enabled.
+    pub fn clear_tcp_nodelay(&mut self) -> &mut Self {
+        self.tcp_nodelay = None;
+        self
+    }
+
+    /// Maximum number of in-flight requests on the connection.
+    pub fn concurrency_limit(&mut self, limit: usize) -> &mut Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Allow any number of in-flight requests.
+    pub fn clear_concurrency_limit(&mut self) -> &mut Self {
+        self.concurrency_limit = None;
+        self
+    }
+
+    /// Maximum size of a decoded response message in bytes.
+    ///
+    /// Defaults to 4MB.
+    pub fn max_decoding_message_size(&mut self, limit: usize) -> &mut Self {
+        self.max_decoding_message_size = Some(limit);
+        self
+    }
+
+    /// Use the default maximum size of decoded response messages.
+    pub fn clear_max_decoding_message_size(&mut self) -> &mut Self {
+        self.max_decoding_message_size = None;
+        self
+    }
+
+    /// Maximum size of an encoded request message in bytes.
+    ///
+    /// Defaults to `usize::MAX`.
+    pub fn max_encoding_message_size(&mut self, limit: usize) -> &mut Self {
+        self.max_encoding_message_size = Some(limit);
+        self
+    }
+
+    /// Use the default maximum size of encoded request messages.
+    pub fn clear_max_encoding_message_size(&mut self) -> &mut Self {
+        self.max_encoding_message_size = None;
+        self
+    }
+
+    /// Set the `user-agent` header sent with every request.
+    pub fn user_agent(&mut self, user_agent: impl ToString) -> &mut Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Send the default `user-agent` header of tonic.
+    pub fn clear_user_agent(&mut self) -> &mut Self {
+        self.user_agent = None;
+        self
+    }
+
+    /// Connect using TLS.
+    ///
+    /// The URL must use the `https` scheme.
+    #[cfg(feature = "tls")]
+    pub fn tls_config(&mut self, tls_config: ClientTlsConfig) -> &mut Self {
+        self.tls_config = Some(tls_config);
+        self
+    }
+
+    /// Connect without configuring TLS.
+    #[cfg(feature = "tls")]
+    pub fn clear_tls_config(&mut self) -> &mut Self {
+        self.tls_config = None;
+        self
+    }
+
+    /// Connect to the server, failing if it is unreachable.
+    ///
+    /// When balancing, fails only if none of the endpoints are reachable.
+    pub async fn connect(&self) -> Result<SpicedbClient> {
+        #[cfg(unix)]
+        if let Some(path) = self.unix_socket_path() {
+            let channel = self
+                .endpoint(UNIX_ENDPOINT_URL.into())?
+                .connect_with_connector(UnixConnector::new(path))
+                .await?;
+            return Ok(self.client(channel));
+        }
+        if !self.is_balanced() {
+            let channel = self.endpoint(self.urls[0].clone())?.connect().await?;
+            return Ok(self.client(channel));
+        }
+
+        let (channel, mut balancer) = Balancer::new(self)?;
+        balancer.refresh().await?;
+        balancer.spawn(false);
+        Ok(self.client(channel))
+    }
+
+    /// Create the client without connecting. The connection is established
+    /// on the first request and re-established after failures.
+    ///
+    /// When balancing, this must be called from within a Tokio runtime.
+    pub fn connect_lazy(&self) -> Result<SpicedbClient> {
+        #[cfg(unix)]
+        if let Some(path) = self.unix_socket_path() {
+            let channel = self
+                .endpoint(UNIX_ENDPOINT_URL.into())?
+                .connect_with_connector_lazy(UnixConnector::new(path));
+            return Ok(self.client(channel));
+        }
+        if !self.is_balanced() {
+            let channel = self.endpoint(self.urls[0].clone())?.connect_lazy();
+            return Ok(self.client(channel));
+        }
+
+        let (channel, mut balancer) = Balancer::new(self)?;
+        balancer.insert_all();
+        balancer.spawn(true);
+        Ok(self.client(channel))
+    }
+
+    /// The socket path if connecting to a single `unix:///path` URL.
+    #[cfg(unix)]
+    fn unix_socket_path(&self) -> Option<std::path::PathBuf> {
+        match self.urls.as_slice() {
+            [url] => unix_socket_path(url),
+            _ => None,
+        }
+    }
+
+    fn is_balanced(&self) -> bool {
+        self.urls.len() > 1 || self.dns_refresh_interval.is_some()
+    }
+
+    /// Endpoint for `addr`, one of the addresses the host of `url` resolved
+    /// to. Requests and TLS verification still use the host from `url`.
+    pub(crate) fn resolved_endpoint(&self, url: &Uri, addr: SocketAddr) -> Result<Endpoint> {
+        let scheme = url.scheme_str().unwrap_or("http");
+        let endpoint = self
+            .endpoint(format!("{scheme}://{addr}").into())?
+            .origin(url.clone());
+        #[cfg(feature = "tls")]
+        if let (Some(tls_config), Some(host)) = (&self.tls_config, url.host()) {
+            return Ok(endpoint.tls_config(tls_config.clone().domain_name(host))?);
+        }
+        Ok(endpoint)
+    }
+
+    pub(crate) fn endpoint(&self, url: Bytes) -> Result<Endpoint> {
+        let mut endpoint = Channel::from_shared(url)?;
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(interval) = self.http2_keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(enabled) = self.keep_alive_while_idle {
+            endpoint = endpoint.keep_alive_while_idle(enabled);
+        }
+        endpoint = endpoint.tcp_keepalive(self.tcp_keepalive);
+        if let Some(enabled) = self.tcp_nodelay {
+            endpoint = endpoint.tcp_nodelay(enabled);
+        }
+        if let Some(limit) = self.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            endpoint = endpoint.user_agent(user_agent.clone())?;
+        }
+        #[cfg(feature = "tls")]
+        if let Some(tls_config) = &self.tls_config {
+            endpoint = endpoint.tls_config(tls_config.clone())?;
+        }
+        Ok(endpoint)
+    }
+
+    fn client(&self, channel: Channel) -> SpicedbClient {
+        let mut client = SpicedbClient::from_channel_and_credentials(
+            channel,
+            self.credentials.clone(),
+            self.retry_policy.clone(),
+        );
+        if let Some(limit) = self.max_decoding_message_size {
+            client = client.max_decoding_message_size(limit);
+        }
+        if let Some(limit) = self.max_encoding_message_size {
+            client = client.max_encoding_message_size(limit);
+        }
+        client
+    }
+}
@@ -0,0 +1,72 @@
+use spicedb_grpc::authzed::api::v0::*;
+use spicedb_grpc::authzed::api::v1::Relationship;
+
+use crate::developer::relation_tuple;
+
+pub trait ValidateRequestBuilder {
+    fn new(schema: impl ToString) -> Self;
+
+    fn schema(&mut self, schema: impl ToString) -> &mut Self;
+
+    fn add_relationship(&mut self, relationship: &Relationship) -> &mut Self;
+
+    fn clear_relationships(&mut self) -> &mut Self;
+
+    /// Expected relations, in the `validation` format of zed validation
+    /// files.
+    fn validation_yaml(&mut self, validation_yaml: impl ToString) -> &mut Self;
+
+    /// Generate the expected relations from the relationships, returned in
+    /// [`Validation::updated_validation_yaml`](crate::developer::Validation::updated_validation_yaml).
+    fn update_validation_yaml(&mut self, update: bool) -> &mut Self;
+
+    /// Assertions, in the `assertions` format of zed validation files.
+    fn assertions_yaml(&mut self, assertions_yaml: impl ToString) -> &mut Self;
+}
+
+impl ValidateRequestBuilder for ValidateRequest {
+    fn new(schema: impl ToString) -> Self {
+        Self {
+            context: Some(RequestContext {
+                schema: schema.to_string(),
+                relationships: vec![],
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn schema(&mut self, schema: impl ToString) -> &mut Self {
+        self.context.get_or_insert_with(Default::default).schema = schema.to_string();
+        self
+    }
+
+    fn add_relationship(&mut self, relationship: &Relationship) -> &mut Self {
+        self.context
+            .get_or_insert_with(Default::default)
+            .relationships
+            .push(relation_tuple(relationship));
+        self
+    }
+
+    fn clear_relationships(&mut self) -> &mut Self {
+        if let Some(context) = self.context.as_mut() {
+            context.relationships.clear();
+        }
+        self
+    }
+
+    fn validation_yaml(&mut self, validation_yaml: impl ToString) -> &mut Self {
+        self.validation_yaml = validation_yaml.to_string();
+        self
+    }
+
+    fn update_validation_yaml(&mut self, update: bool) -> &mut Self {
+        self.update_validation_yaml = update;
+        self
+    }
+
+    fn assertions_yaml(&mut self, assertions_yaml: impl ToString) -> &mut Self {
+        self.assertions_yaml = assertions_yaml.to_string();
+        self
+    }
+}
@@ -0,0 +1,331 @@
+use spicedb_grpc::authzed::api::v1::*;
+
+use crate::builder::RelationshipFilterBuilder;
+use crate::types::ConsistencyRequirement;
+
+pub trait ExperimentalRegisterRelationshipCounterRequestBuilder {
+    /// Create a request registering the counter `name`, which must be 3 to
+    /// 64 lowercase letters, digits or underscores, starting with a letter.
+    fn new(name: impl ToString) -> Self;
+
+    fn name(&mut self, name: impl ToString) -> &mut Self;
+
+    fn relationship_filter(&mut self) -> &mut RelationshipFilter;
+}
+
+impl ExperimentalRegisterRelationshipCounterRequestBuilder
+    for ExperimentalRegisterRelationshipCounterRequest
+{
+    fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            relationship_filter: None,
+        }
+    }
+
+    fn name(&mut self, name: impl ToString) -> &mut Self {
+        self.name = name.to_string();
+        self
+    }
+
+    fn relationship_filter(&mut self) -> &mut RelationshipFilter {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+    }
+}
+
+impl RelationshipFilterBuilder for ExperimentalRegisterRelationshipCounterRequest {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn resource_type(&mut self, resource_type: impl ToString) -> &mut Self {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .resource_type(resource_type);
+        self
+    }
+
+    fn clear_resource_type(&mut self) -> &mut Self {
+        if let Some(filter) = self.relationship_filter.as_mut() {
+            filter.clear_resource_type();
+        }
+        self
+    }
+
+    fn resource_id(&mut self, resource_id: impl ToString) -> &mut Self {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .resource_id(resource_id);
+        self
+    }
+
+    fn clear_resource_id(&mut self) -> &mut Self {
+        if let Some(filter) = self.relationship_filter.as_mut() {
+            filter.clear_resource_id();
+        }
+        self
+    }
+
+    fn resource_id_prefix(&mut self, resource_id_prefix: impl ToString) -> &mut Self {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .resource_id_prefix(resource_id_prefix);
+        self
+    }
+
+    fn clear_resource_id_prefix(&mut self) -> &mut Self {
+        if let Some(filter) = self.relationship_filter.as_mut() {
+            filter.clear_resource_id_prefix();
+        }
+        self
+    }
+
+    fn relation(&mut self, relation: impl ToString) -> &mut Self {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .relation(relation);
+        self
+    }
+
+    fn clear_relation(&mut self) -> &mut Self {
+        if let Some(filter) = self.relationship_filter.as_mut() {
+            filter.clear_relation();
+        }
+        self
+    }
+
+    fn subject_type(&mut self, subject_type: impl ToString) -> &mut SubjectFilter {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .subject_type(subject_type)
+    }
+
+    fn subject_filter(&mut self) -> Option<&mut SubjectFilter> {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .subject_filter()
+    }
+
+    fn clear_subject_filter(&mut self) -> &mut Self {
+        self.relationship_filter
+            .get_or_insert_with(Default::default)
+            .clear_subject_filter();
+        self
+    }
+}
+
+pub trait ExperimentalCountRelationshipsRequestBuilder {
+    fn new(name: impl ToString) -> Self;
+}
+
+impl ExperimentalCountRelationshipsRequestBuilder for ExperimentalCountRelationshipsRequest {
+    fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+pub trait ExperimentalUnregisterRelationshipCounterRequestBuilder {
+    fn new(name: impl ToString) -> Self;
+}
+
+impl ExperimentalUnregisterRelationshipCounterRequestBuilder
+    for ExperimentalUnregisterRelationshipCounterRequest
+{
+    fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+pub trait ExperimentalReflectSchemaRequestBuilder {
+    fn new() -> Self;
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self;
+
+    fn clear_consistency(&mut self) -> &mut Self;
+
+    /// Add a filter. The schema is reduced to the parts matching any of the
+    /// filters.
+    fn add_filter(&mut self) -> &mut ExpSchemaFilter;
+
+    fn clear_filters(&mut self) -> &mut Self;
+}
+
+impl ExperimentalReflectSchemaRequestBuilder for ExperimentalReflectSchemaRequest {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self {
+        self.consistency = Some(Consistency {
+            requirement: Some(requirement),
+        });
+        self
+    }
+
+    fn clear_consistency(&mut self) -> &mut Self {
+        self.consistency = None;
+        self
+    }
+
+    fn add_filter(&mut self) -> &mut ExpSchemaFilter {
+        self.optional_filters.push(Default::default());
+        self.optional_filters.last_mut().unwrap()
+    }
+
+    fn clear_filters(&mut self) -> &mut Self {
+        self.optional_filters.clear();
+        self
+    }
+}
+
+pub trait ExperimentalComputablePermissionsRequestBuilder {
+    /// Create a request for the permissions computed from `relation` of
+    /// `definition`.
+    fn new(definition: impl ToString, relation: impl ToString) -> Self;
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self;
+
+    fn clear_consistency(&mut self) -> &mut Self;
+
+    /// Only return permissions of the definition `definition`.
+    fn definition_name_filter(&mut self, definition: impl ToString) -> &mut Self;
+
+    fn clear_definition_name_filter(&mut self) -> &mut Self;
+}
+
+impl ExperimentalComputablePermissionsRequestBuilder for ExperimentalComputablePermissionsRequest {
+    fn new(definition: impl ToString, relation: impl ToString) -> Self {
+        Self {
+            consistency: None,
+            definition_name: definition.to_string(),
+            relation_name: relation.to_string(),
+            optional_definition_name_filter: String::new(),
+        }
+    }
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self {
+        self.consistency = Some(Consistency {
+            requirement: Some(requirement),
+        });
+        self
+    }
+
+    fn clear_consistency(&mut self) -> &mut Self {
+        self.consistency = None;
+        self
+    }
+
+    fn definition_name_filter(&mut self, definition: impl ToString) -> &mut Self {
+        self.optional_definition_name_filter = definition.to_string();
+        self
+    }
+
+    fn clear_definition_name_filter(&mut self) -> &mut Self {
+        self.optional_definition_name_filter.clear();
+        self
+    }
+}
+
+pub trait ExperimentalDependentRelationsRequestBuilder {
+    /// Create a request for the relations and permissions `permission` of
+    /// `definition` depends on.
+    fn new(definition: impl ToString, permission: impl ToString) -> Self;
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self;
+
+    fn clear_consistency(&mut self) -> &mut Self;
+}
+
+impl ExperimentalDependentRelationsRequestBuilder for ExperimentalDependentRelationsRequest {
+    fn new(definition: impl ToString, permission: impl ToString) -> Self {
+        Self {
+            consistency: None,
+            definition_name: definition.to_string(),
+            permission_name: permission.to_string(),
+        }
+    }
+
+    fn consistency(&mut self, requirement: ConsistencyRequirement) -> &mut Self {
+        self.consistency = Some(Consistency {
+            requirement: Some(requirement),
+        });
+        self
+    }
+
+    fn clear_consistency(&mut self) -> &mut Self {
+        self.consistency = None;
+        self
+    }
+}
+
+/// Filters on definition, caveat, relation and permission name prefixes.
+pub trait ExpSchemaFilterBuilder {
+    fn new() -> Self;
+
+    fn definition_name_prefix(&mut self, prefix: impl ToString) -> &mut Self;
+
+    fn clear_definition_name_prefix(&mut self) -> &mut Self;
+
+    fn caveat_name_prefix(&mut self, prefix: impl ToString) -> &mut Self;
+
+    fn clear_caveat_name_prefix(&mut self) -> &mut Self;
+
+    fn relation_name_prefix(&mut self, prefix: impl ToString) -> &mut Self;
+
+    fn clear_relation_name_prefix(&mut self) -> &mut Self;
+
+    fn permission_name_prefix(&mut self, prefix: impl ToString) -> &mut Self;
+
+    fn clear_permission_name_prefix(&mut self) -> &mut Self;
+}
+
+impl ExpSchemaFilterBuilder for ExpSchemaFilter {
+    fn new() -> Self {
+        Default::default()
+    }
+
+    fn definition_name_prefix(&mut self, prefix: impl ToString) -> &mut Self {
+        self.optional_definition_name_filter = prefix.to_string();
+        self
+    }
+
+    fn clear_definition_name_prefix(&mut self) -> &mut Self {
+        self.optional_definition_name_filter.clear();
+        self
+    }
+
+    fn caveat_name_prefix(&mut self, prefix: impl ToString) -> &mut Self {
+        self.optional_caveat_name_filter = prefix.to_string();
+        self
+    }
+
+    fn clear_caveat_name_prefix(&mut self) -> &mut Self {
+        self.optional_caveat_name_filter.clear();
+        self
+    }
+
+    fn relation_name_prefix(&mut self, prefix: impl ToString) -> &mut Self {
+        self.optional_relation_name_filter = prefix.to_string();
+        self
+    }
+
+    fn clear_relation_name_prefix(&mut self) -> &mut Self {
+        self.optional_relation_name_filter.clear();
+        self
+    }
+
+    fn permission_name_prefix(&mut self, prefix: impl ToString) -> &mut Self {
+        self.optional_permission_name_filter = prefix.to_string();
+        self
+    }
+
+    fn clear_permission_name_prefix(&mut self) -> &mut Self {
+        self.optional_permission_name_filter.clear();
+        self
+    }
+}
@@ -1,3 +1,11 @@
+mod client;
+#[cfg(feature = "developer")]
+mod developer;
+mod experimental;
 mod permissions;
 
+pub use client::*;
+#[cfg(feature = "developer")]
+pub use developer::*;
+pub use experimental::*;
 pub use permissions::*;
@@ -0,0 +1,318 @@
+//! Helpers for the bulk import and export APIs.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use spicedb_grpc::authzed::api::v1::{
+    BulkExportRelationshipsResponse, BulkImportRelationshipsRequest, Cursor, Relationship,
+};
+use tokio::time::{sleep, Sleep};
+use tokio_stream::Stream;
+use tonic::Status;
+
+use crate::result::{Error, Result};
+use crate::retry::RetryPolicy;
+
+/// Default number of relationships sent in each bulk import message.
+pub const DEFAULT_BULK_IMPORT_CHUNK_SIZE: usize = 1_000;
+
+/// Groups a stream of relationships into bulk import requests of up to
+/// `chunk_size` relationships, reporting the number of relationships sent so
+/// far after each request.
+pub(crate) struct ImportChunks<S> {
+    relationships: Pin<Box<S>>,
+    chunk: Vec<Relationship>,
+    chunk_size: usize,
+    sent: u64,
+    progress: Box<dyn FnMut(u64) + Send>,
+    done: bool,
+}
+
+impl<S> ImportChunks<S>
+where
+    S: Stream<Item = Relationship>,
+{
+    pub(crate) fn new(
+        relationships: S,
+        chunk_size: usize,
+        progress: impl FnMut(u64) + Send + 'static,
+    ) -> Self {
+        let chunk_size = chunk_size.max(1);
+        Self {
+            relationships: Box::pin(relationships),
+            chunk: Vec::with_capacity(chunk_size),
+            chunk_size,
+            sent: 0,
+            progress: Box::new(progress),
+            done: false,
+        }
+    }
+
+    fn flush(&mut self) -> BulkImportRelationshipsRequest {
+        let relationships = std::mem::replace(&mut self.chunk, Vec::with_capacity(self.chunk_size));
+        self.sent += relationships.len() as u64;
+        (self.progress)(self.sent);
+        BulkImportRelationshipsRequest { relationships }
+    }
+}
+
+impl<S> Stream for ImportChunks<S>
+where
+    S: Stream<Item = Relationship>,
+{
+    type Item = BulkImportRelationshipsRequest;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            match self.relationships.as_mut().poll_next(cx) {
+                Poll::Ready(Some(relationship)) => {
+                    self.chunk.push(relationship);
+                    if self.chunk.len() >= self.chunk_size {
+                        return Poll::Ready(Some(self.flush()));
+                    }
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    if !self.chunk.is_empty() {
+                        return Poll::Ready(Some(self.flush()));
+                    }
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<S> fmt::Debug for ImportChunks<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImportChunks")
+            .field("chunk_size", &self.chunk_size)
+            .field("sent", &self.sent)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) type ExportPages =
+    Pin<Box<dyn Stream<Item = Result<BulkExportRelationshipsResponse, Status>> + Send>>;
+
+pub(crate) type ExportConnect = Box<
+    dyn FnMut(Option<Cursor>) -> Pin<Box<dyn Future<Output = Result<ExportPages>> + Send>> + Send,
+>;
+
+enum ExportState {
+    Connect,
+    Connecting(Pin<Box<dyn Future<Output = Result<ExportPages>> + Send>>),
+    Receiving(ExportPages),
+    Backoff(Pin<Box<Sleep>>),
+    Done,
+}
+
+/// Stream of the relationships returned by
+/// [`SpicedbClient::bulk_export`](crate::SpicedbClient::bulk_export).
+///
+/// When the export fails with a transient error, it is resumed after the
+/// last page received, according to the client's [`RetryPolicy`].
+///
+/// The [`cursor`](Self::cursor) can be saved to resume an interrupted export
+/// later, by setting it as the `optional_cursor` of a new request. The export
+/// is resumed after the last page that was yielded completely, so some
+/// relationships may be yielded twice.
+pub struct BulkExport {
+    connect: ExportConnect,
+    retry_policy: RetryPolicy,
+    state: ExportState,
+    attempt: u32,
+    relationships: VecDeque<Relationship>,
+    /// Cursor after the page currently being yielded.
+    next_cursor: Option<Cursor>,
+    cursor: Option<Cursor>,
+}
+
+impl BulkExport {
+    pub(crate) fn new(
+        cursor: Option<Cursor>,
+        retry_policy: RetryPolicy,
+        connect: ExportConnect,
+    ) -> Self {
+        Self {
+            connect,
+            retry_policy,
+            state: ExportState::Connect,
+            attempt: 0,
+            relationships: VecDeque::new(),
+            next_cursor: None,
+            cursor,
+        }
+    }
+
+    /// Cursor after the last page of relationships yielded completely.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+}
+
+impl Stream for BulkExport {
+    type Item = Result<Relationship>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(relationship) = this.relationships.pop_front() {
+                return Poll::Ready(Some(Ok(relationship)));
+            }
+            if let Some(cursor) = this.next_cursor.take() {
+                this.cursor = Some(cursor);
+            }
+
+            match &mut this.state {
+                ExportState::Connect => {
+                    this.state = ExportState::Connecting((this.connect)(this.cursor.clone()));
+                }
+                ExportState::Connecting(connecting) => match ready!(connecting.as_mut().poll(cx)) {
+                    Ok(pages) => this.state = ExportState::Receiving(pages),
+                    Err(err) => {
+                        this.state = ExportState::Done;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                ExportState::Receiving(pages) => match ready!(pages.as_mut().poll_next(cx)) {
+                    Some(Ok(page)) => {
+                        this.attempt = 0;
+                        this.relationships.extend(page.relationships);
+                        this.next_cursor = page.after_result_cursor;
+                    }
+                    Some(Err(status)) => {
+                        let err = Error::from(status);
+                        this.attempt += 1;
+                        if !this.retry_policy.should_retry(this.attempt, &err) {
+                            this.state = ExportState::Done;
+                            return Poll::Ready(Some(Err(err)));
+                        }
+                        let backoff = this.retry_policy.backoff(this.attempt);
+                        this.state = ExportState::Backoff(Box::pin(sleep(backoff)));
+                    }
+                    None => this.state = ExportState::Done,
+                },
+                ExportState::Backoff(backoff) => {
+                    ready!(backoff.as_mut().poll(cx));
+                    this.state = ExportState::Connect;
+                }
+                ExportState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BulkExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BulkExport")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+    use std::time::Duration;
+
+    use tokio::test;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    fn relationship(n: usize) -> Relationship {
+        Relationship {
+            relation: format!("relation{n}"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    pub async fn test_import_chunks() {
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let chunks = ImportChunks::new(tokio_stream::iter((0..7).map(relationship)), 3, {
+            let progress = progress.clone();
+            move |sent| progress.lock().unwrap().push(sent)
+        });
+
+        let sizes: Vec<_> = chunks
+            .map(|request| request.relationships.len())
+            .collect()
+            .await;
+        assert_eq!(sizes, [3, 3, 1]);
+        assert_eq!(*progress.lock().unwrap(), [3, 6, 7]);
+
+        let chunks = ImportChunks::new(tokio_stream::iter([]), 3, |_| {});
+        assert!(chunks.collect::<Vec<_>>().await.is_empty());
+    }
+
+    fn page(cursor: &str, relations: &[usize]) -> Result<BulkExportRelationshipsResponse, Status> {
+        Ok(BulkExportRelationshipsResponse {
+            after_result_cursor: Some(Cursor {
+                token: cursor.to_string(),
+            }),
+            relationships: relations.iter().copied().map(relationship).collect(),
+        })
+    }
+
+    #[test]
+    pub async fn test_bulk_export_resume() {
+        let connections = Arc::new(AtomicUsize::new(0));
+        let connect: ExportConnect = Box::new({
+            let connections = connections.clone();
+            move |cursor| {
+                let connection = connections.fetch_add(1, Ordering::SeqCst);
+                let pages: Vec<_> = match connection {
+                    0 => {
+                        assert_eq!(cursor, None);
+                        vec![page("1", &[0, 1]), Err(Status::unavailable("restarting"))]
+                    }
+                    _ => {
+                        assert_eq!(cursor.unwrap().token, "1");
+                        vec![page("2", &[2])]
+                    }
+                };
+                Box::pin(async move { Ok(Box::pin(tokio_stream::iter(pages)) as ExportPages) })
+            }
+        });
+
+        let mut policy = RetryPolicy::new();
+        policy.initial_backoff(Duration::from_millis(1));
+        let mut export = BulkExport::new(None, policy, connect);
+
+        let mut relations = Vec::new();
+        while let Some(relationship) = export.next().await {
+            relations.push(relationship.unwrap().relation);
+        }
+        assert_eq!(relations, ["relation0", "relation1", "relation2"]);
+        assert_eq!(export.cursor().unwrap().token, "2");
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    pub async fn test_bulk_export_error() {
+        let connect: ExportConnect = Box::new(|_| {
+            let pages = vec![page("1", &[0]), Err(Status::permission_denied("denied"))];
+            Box::pin(async move { Ok(Box::pin(tokio_stream::iter(pages)) as ExportPages) })
+        });
+        let mut export = BulkExport::new(None, RetryPolicy::new(), connect);
+
+        assert!(export.next().await.unwrap().is_ok());
+        let err = export.next().await.unwrap().unwrap_err();
+        assert_eq!(err.code(), Some(tonic::Code::PermissionDenied));
+        assert!(export.next().await.is_none());
+        assert_eq!(export.cursor().unwrap().token, "1");
+    }
+}
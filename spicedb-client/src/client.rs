@@ -1,34 +1,98 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::future::Future;
+
 use bytes::Bytes;
+use http::Uri;
+#[cfg(feature = "materialize")]
+use spicedb_grpc::authzed::api::materialize::v0::{
+    watch_permission_sets_service_client::WatchPermissionSetsServiceClient,
+    watch_permissions_service_client::WatchPermissionsServiceClient, Cursor as MaterializeCursor,
+    LookupPermissionSetsRequest, LookupPermissionSetsResponse, WatchPermissionSetsRequest,
+    WatchPermissionsRequest,
+};
 use spicedb_grpc::authzed::api::v1::{
+    experimental_service_client::ExperimentalServiceClient,
     permissions_service_client::PermissionsServiceClient,
     schema_service_client::SchemaServiceClient, watch_service_client::WatchServiceClient, *,
 };
+#[cfg(feature = "alpha")]
+use spicedb_grpc::authzed::api::v1alpha1::{
+    watch_resources_service_client::WatchResourcesServiceClient, WatchResourcesRequest,
+    WatchResourcesResponse,
+};
+use tokio_stream::Stream;
+#[cfg(feature = "tls")]
+use tonic::transport::ClientTlsConfig;
 use tonic::{
-    metadata::{Ascii, MetadataValue},
-    service::{interceptor::InterceptedService, Interceptor},
+    body::BoxBody,
+    client::GrpcService,
+    codegen::{Body, StdError},
     transport::Channel,
-    Request, Status, Streaming,
+    Code, Request, Response, Status, Streaming,
 };
 
+#[cfg(feature = "alpha")]
+use crate::alpha::{PermissionUpdates, WatchResourcesResponses};
+use crate::builder::{
+    ExperimentalCountRelationshipsRequestBuilder,
+    ExperimentalRegisterRelationshipCounterRequestBuilder,
+    ExperimentalUnregisterRelationshipCounterRequestBuilder, SpicedbClientBuilder,
+};
+use crate::bulk::{
+    BulkExport, ExportConnect, ExportPages, ImportChunks, DEFAULT_BULK_IMPORT_CHUNK_SIZE,
+};
+use crate::credentials::{authorized_request, Credentials, CredentialsProvider};
+#[cfg(feature = "materialize")]
+use crate::materialize::{
+    Events, LookupConnect, LookupPage, LookupPermissionSets, PermissionEvents, PermissionSetEvents,
+};
 use crate::result::Result;
+use crate::retry::{call_with_retry, IdempotentRequest, RetryPolicy};
+use crate::schema::{Schema, SchemaDiff};
+use crate::types::RelationshipCount;
 
 /// SpiceDB client
+///
+/// All methods take `&self`, so a single client can be shared across tasks,
+/// for example behind an [`Arc`](std::sync::Arc). Clones are cheap and share
+/// the same connections.
+///
+/// The client sends requests over a [`Channel`] by default, but can use any
+/// [`tower::Service`] accepting `http::Request<BoxBody>`, such as a custom
+/// hyper client, a middleware stack or an in-process server, see
+/// [`SpicedbClient::new`].
 #[derive(Clone, Debug)]
-pub struct SpicedbClient {
-    pub channel: Channel,
-    schemas: SchemaServiceClient<InterceptedService<Channel, SpicedbMiddleware>>,
-    permissions: PermissionsServiceClient<InterceptedService<Channel, SpicedbMiddleware>>,
-    watch: WatchServiceClient<InterceptedService<Channel, SpicedbMiddleware>>,
+pub struct SpicedbClient<T = Channel> {
+    transport: T,
+    credentials: Option<Credentials>,
+    retry_policy: RetryPolicy,
+    schemas: SchemaServiceClient<T>,
+    permissions: PermissionsServiceClient<T>,
+    watch: WatchServiceClient<T>,
+    experimental: ExperimentalServiceClient<T>,
+    #[cfg(feature = "alpha")]
+    watch_resources: WatchResourcesServiceClient<T>,
+    #[cfg(feature = "materialize")]
+    watch_permissions: WatchPermissionsServiceClient<T>,
+    #[cfg(feature = "materialize")]
+    watch_permission_sets: WatchPermissionSetsServiceClient<T>,
 }
 
 impl SpicedbClient {
+    /// Create a [`SpicedbClientBuilder`] to configure the connection to the
+    /// server at `url`.
+    pub fn builder(url: impl Into<Bytes>) -> SpicedbClientBuilder {
+        SpicedbClientBuilder::new(url)
+    }
+
     /// Create a new [`SpicedbClient`] from the server URL and a preshared key.
     ///
     /// ```rust
     /// # use spicedb_client::SpicedbClient;
     /// #
     /// # async fn create_client() {
-    /// let mut client =
+    /// let client =
     ///     SpicedbClient::from_url_and_preshared_key("http://localhost:50051", "spicedb")
     ///         .await
     ///         .unwrap();
@@ -38,25 +102,230 @@ impl SpicedbClient {
         url: impl Into<Bytes>,
         preshared_key: impl ToString,
     ) -> Result<Self> {
-        let interceptor = SpicedbMiddleware {
-            preshared_key: Box::new(format!("bearer {}", preshared_key.to_string()).parse()?),
-        };
+        SpicedbClientBuilder::new(url)
+            .preshared_key(preshared_key)
+            .connect()
+            .await
+    }
+
+    /// Create a new [`SpicedbClient`] from the server URL, a preshared key and
+    /// a TLS configuration.
+    ///
+    /// The URL must use the `https` scheme. The TLS configuration can provide
+    /// a custom root CA, a client identity for mutual TLS, and override the
+    /// domain name used for SNI and certificate verification.
+    ///
+    /// ```rust
+    /// # use spicedb_client::{Certificate, ClientTlsConfig, Identity, SpicedbClient};
+    /// #
+    /// # async fn create_client(ca: &[u8], cert: &[u8], key: &[u8]) {
+    /// let tls = ClientTlsConfig::new()
+    ///     .ca_certificate(Certificate::from_pem(ca))
+    ///     .identity(Identity::from_pem(cert, key))
+    ///     .domain_name("spicedb.internal");
+    ///
+    /// let client = SpicedbClient::from_url_and_preshared_key_with_tls(
+    ///     "https://localhost:50051",
+    ///     "spicedb",
+    ///     tls,
+    /// )
+    /// .await
+    /// .unwrap();
+    /// # }
+    /// ```
+    #[cfg(feature = "tls")]
+    pub async fn from_url_and_preshared_key_with_tls(
+        url: impl Into<Bytes>,
+        preshared_key: impl ToString,
+        tls: ClientTlsConfig,
+    ) -> Result<Self> {
+        SpicedbClientBuilder::new(url)
+            .preshared_key(preshared_key)
+            .tls_config(tls)
+            .connect()
+            .await
+    }
+
+    /// Create a new [`SpicedbClient`] connected over the Unix domain socket at
+    /// `path`, such as one exposed by a SpiceDB sidecar.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// #
+    /// # async fn create_client() {
+    /// let client =
+    ///     SpicedbClient::from_unix_socket_and_preshared_key("/run/spicedb.sock", "spicedb")
+    ///         .await
+    ///         .unwrap();
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub async fn from_unix_socket_and_preshared_key(
+        path: impl AsRef<std::path::Path>,
+        preshared_key: impl ToString,
+    ) -> Result<Self> {
+        SpicedbClientBuilder::new(format!("unix://{}", path.as_ref().display()))
+            .preshared_key(preshared_key)
+            .connect()
+            .await
+    }
 
-        let channel = Channel::from_shared(url)?.connect().await?;
+    pub(crate) fn from_channel_and_credentials(
+        channel: Channel,
+        credentials: Option<Credentials>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        let mut client = SpicedbClient::new(channel).with_retry_policy(retry_policy);
+        client.credentials = credentials;
+        client
+    }
+}
 
-        let schemas = SchemaServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+impl<T> SpicedbClient<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Create a new [`SpicedbClient`] sending requests over `transport`.
+    ///
+    /// The transport must set the scheme and authority of the request URIs,
+    /// as [`Channel`] does, otherwise use [`SpicedbClient::with_origin`].
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use tonic::transport::Endpoint;
+    /// #
+    /// # fn create_client() {
+    /// let channel = Endpoint::from_static("http://localhost:50051").connect_lazy();
+    /// let client = SpicedbClient::new(channel).with_credentials(
+    ///     spicedb_client::credentials::StaticKey::new("spicedb"),
+    /// );
+    /// # }
+    /// ```
+    pub fn new(transport: T) -> Self {
+        SpicedbClient {
+            transport: transport.clone(),
+            credentials: None,
+            retry_policy: RetryPolicy::default(),
+            schemas: SchemaServiceClient::new(transport.clone()),
+            permissions: PermissionsServiceClient::new(transport.clone()),
+            watch: WatchServiceClient::new(transport.clone()),
+            #[cfg(feature = "alpha")]
+            watch_resources: WatchResourcesServiceClient::new(transport.clone()),
+            #[cfg(feature = "materialize")]
+            watch_permissions: WatchPermissionsServiceClient::new(transport.clone()),
+            #[cfg(feature = "materialize")]
+            watch_permission_sets: WatchPermissionSetsServiceClient::new(transport.clone()),
+            experimental: ExperimentalServiceClient::new(transport),
+        }
+    }
 
-        let permissions =
-            PermissionsServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+    /// Create a new [`SpicedbClient`] sending requests over `transport`, with
+    /// `origin` as the scheme and authority of the request URIs.
+    pub fn with_origin(transport: T, origin: Uri) -> Self {
+        SpicedbClient {
+            transport: transport.clone(),
+            credentials: None,
+            retry_policy: RetryPolicy::default(),
+            schemas: SchemaServiceClient::with_origin(transport.clone(), origin.clone()),
+            permissions: PermissionsServiceClient::with_origin(transport.clone(), origin.clone()),
+            watch: WatchServiceClient::with_origin(transport.clone(), origin.clone()),
+            #[cfg(feature = "alpha")]
+            watch_resources: WatchResourcesServiceClient::with_origin(
+                transport.clone(),
+                origin.clone(),
+            ),
+            #[cfg(feature = "materialize")]
+            watch_permissions: WatchPermissionsServiceClient::with_origin(
+                transport.clone(),
+                origin.clone(),
+            ),
+            #[cfg(feature = "materialize")]
+            watch_permission_sets: WatchPermissionSetsServiceClient::with_origin(
+                transport.clone(),
+                origin.clone(),
+            ),
+            experimental: ExperimentalServiceClient::with_origin(transport, origin),
+        }
+    }
 
-        let watch = WatchServiceClient::with_interceptor(channel.clone(), interceptor.clone());
+    /// The transport requests are sent over, such as the [`Channel`] to the
+    /// server.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
 
-        Ok(SpicedbClient {
-            channel,
-            schemas,
-            permissions,
-            watch,
-        })
+    /// Authenticate every request with a token from `provider`.
+    pub fn with_credentials(mut self, provider: impl CredentialsProvider) -> Self {
+        self.credentials = Some(Credentials::new(provider));
+        self
+    }
+
+    /// Retry policy for transient failures of idempotent requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit the maximum size of a decoded response message.
+    ///
+    /// Defaults to 4MB.
+    pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+        self.schemas = self.schemas.max_decoding_message_size(limit);
+        self.permissions = self.permissions.max_decoding_message_size(limit);
+        self.watch = self.watch.max_decoding_message_size(limit);
+        self.experimental = self.experimental.max_decoding_message_size(limit);
+        #[cfg(feature = "alpha")]
+        {
+            self.watch_resources = self.watch_resources.max_decoding_message_size(limit);
+        }
+        #[cfg(feature = "materialize")]
+        {
+            self.watch_permissions = self.watch_permissions.max_decoding_message_size(limit);
+            self.watch_permission_sets =
+                self.watch_permission_sets.max_decoding_message_size(limit);
+        }
+        self
+    }
+
+    /// Limit the maximum size of an encoded request message.
+    ///
+    /// Defaults to `usize::MAX`.
+    pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+        self.schemas = self.schemas.max_encoding_message_size(limit);
+        self.permissions = self.permissions.max_encoding_message_size(limit);
+        self.watch = self.watch.max_encoding_message_size(limit);
+        self.experimental = self.experimental.max_encoding_message_size(limit);
+        #[cfg(feature = "alpha")]
+        {
+            self.watch_resources = self.watch_resources.max_encoding_message_size(limit);
+        }
+        #[cfg(feature = "materialize")]
+        {
+            self.watch_permissions = self.watch_permissions.max_encoding_message_size(limit);
+            self.watch_permission_sets =
+                self.watch_permission_sets.max_encoding_message_size(limit);
+        }
+        self
+    }
+
+    /// Wrap a message in a [`Request`] authorized with the client's
+    /// credentials.
+    async fn request<M>(&self, message: M) -> Result<Request<M>> {
+        authorized_request(self.credentials.as_ref(), message).await
+    }
+
+    /// Send a request, retrying transient failures according to the client's
+    /// [`RetryPolicy`] if the request is idempotent.
+    async fn call<M, R, F, Fut>(&self, message: M, send: F) -> Result<R>
+    where
+        M: Clone + IdempotentRequest + Any,
+        F: FnMut(Request<M>) -> Fut,
+        Fut: Future<Output = Result<Response<R>, Status>>,
+    {
+        call_with_retry(self.credentials.as_ref(), &self.retry_policy, message, send).await
     }
 
     /// Read the current Object Definitions for a Permissions System.
@@ -64,58 +333,64 @@ impl SpicedbClient {
     /// Errors include:
     /// - INVALID_ARGUMENT: a provided value has failed to semantically validate
     /// - NOT_FOUND: no schema has been defined
-    pub async fn read_schema(&mut self) -> Result<ReadSchemaResponse> {
-        let response = self
-            .schemas
-            .read_schema(ReadSchemaRequest {})
-            .await
-            .unwrap()
-            .into_inner();
+    pub async fn read_schema(&self) -> Result<ReadSchemaResponse> {
+        let schemas = self.schemas.clone();
+        self.call(ReadSchemaRequest {}, |request| {
+            let mut schemas = schemas.clone();
+            async move { schemas.read_schema(request).await }
+        })
+        .await
+    }
 
-        Ok(response)
+    /// Read the current Object Definitions for a Permissions System, returning
+    /// `None` if no schema has been defined.
+    pub async fn read_schema_opt(&self) -> Result<Option<ReadSchemaResponse>> {
+        match self.read_schema().await {
+            Ok(response) => Ok(Some(response)),
+            Err(err) if err.code() == Some(Code::NotFound) => Ok(None),
+            Err(err) => Err(err),
+        }
     }
 
     /// Overwrite the current Object Definitions for a Permissions System.
-    pub async fn write_schema(&mut self, schema: impl ToString) -> Result<WriteSchemaResponse> {
-        let response = self
-            .schemas
-            .write_schema(WriteSchemaRequest {
-                schema: schema.to_string(),
-            })
-            .await?
-            .into_inner();
-
-        Ok(response)
+    pub async fn write_schema(&self, schema: impl ToString) -> Result<WriteSchemaResponse> {
+        let request = WriteSchemaRequest {
+            schema: schema.to_string(),
+        };
+        let schemas = self.schemas.clone();
+        self.call(request, |request| {
+            let mut schemas = schemas.clone();
+            async move { schemas.write_schema(request).await }
+        })
+        .await
     }
 
     /// Read a set of the relationships matching one or more filters.
     pub async fn read_relationships(
-        &mut self,
+        &self,
         request: ReadRelationshipsRequest,
     ) -> Result<Streaming<ReadRelationshipsResponse>> {
-        let stream = self
-            .permissions
-            .read_relationships(request)
-            .await?
-            .into_inner();
-
-        Ok(stream)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.read_relationships(request).await }
+        })
+        .await
     }
 
     /// Atomically write and/or delete a set of specified relationships. An
     /// optional set of preconditions can be provided that must be satisfied for
     /// the operation to commit.
     pub async fn write_relationships(
-        &mut self,
+        &self,
         request: WriteRelationshipsRequest,
     ) -> Result<WriteRelationshipsResponse> {
-        let response = self
-            .permissions
-            .write_relationships(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.write_relationships(request).await }
+        })
+        .await
     }
 
     /// Atomically bulk delete all relationships matching the provided filter.
@@ -123,91 +398,85 @@ impl SpicedbClient {
     /// succeed. An optional set of preconditions can be provided that must be
     /// satisfied for the operation to commit.
     pub async fn delete_relationships(
-        &mut self,
+        &self,
         request: DeleteRelationshipsRequest,
     ) -> Result<DeleteRelationshipsResponse> {
-        let response = self
-            .permissions
-            .delete_relationships(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.delete_relationships(request).await }
+        })
+        .await
     }
 
     /// Determine, for a given resource, whether a subject computes to having a
     /// permission or is a direct member of a particular relation.
     pub async fn check_permission(
-        &mut self,
+        &self,
         request: CheckPermissionRequest,
     ) -> Result<CheckPermissionResponse> {
-        let response = self
-            .permissions
-            .check_permission(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.check_permission(request).await }
+        })
+        .await
     }
 
     /// Evaluate the given list of permission checks.
     pub async fn check_bulk_permissions(
-        &mut self,
+        &self,
         request: CheckBulkPermissionsRequest,
     ) -> Result<CheckBulkPermissionsResponse> {
-        let response = self
-            .permissions
-            .check_bulk_permissions(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.check_bulk_permissions(request).await }
+        })
+        .await
     }
 
     /// Reveal the graph structure for a resource's permission or relation. This
     /// RPC does not recurse infinitely deep and may require multiple calls to
     /// fully unnest a deeply nested graph.
     pub async fn expand_permission_tree(
-        &mut self,
+        &self,
         request: ExpandPermissionTreeRequest,
     ) -> Result<ExpandPermissionTreeResponse> {
-        let response = self
-            .permissions
-            .expand_permission_tree(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.expand_permission_tree(request).await }
+        })
+        .await
     }
 
     /// Return all the resources of a given type that a subject can access
     /// whether via a computed permission or relation membership.
     pub async fn lookup_resources(
-        &mut self,
+        &self,
         request: LookupResourcesRequest,
     ) -> Result<Streaming<LookupResourcesResponse>> {
-        let response = self
-            .permissions
-            .lookup_resources(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.lookup_resources(request).await }
+        })
+        .await
     }
 
     /// Return all the subjects of a given type that have access whether via a
     /// computed permission or relation membership.
     pub async fn lookup_subjects(
-        &mut self,
+        &self,
         request: LookupSubjectsRequest,
     ) -> Result<Streaming<LookupSubjectsResponse>> {
-        let response = self
-            .permissions
-            .lookup_subjects(request)
-            .await?
-            .into_inner();
-
-        Ok(response)
+        let permissions = self.permissions.clone();
+        self.call(request, |request| {
+            let mut permissions = permissions.clone();
+            async move { permissions.lookup_subjects(request).await }
+        })
+        .await
     }
 
     /// Watch the database for mutations.
@@ -220,24 +489,587 @@ impl SpicedbClient {
     ///     https://authzed.com/docs/spicedb/concepts/datastores#memdb
     /// [postgres]:
     ///     https://authzed.com/docs/spicedb/concepts/datastores#postgresql
-    pub async fn watch(&mut self, request: WatchRequest) -> Result<Streaming<WatchResponse>> {
-        let response = self.watch.watch(request).await?.into_inner();
+    pub async fn watch(&self, request: WatchRequest) -> Result<Streaming<WatchResponse>> {
+        let watch = self.watch.clone();
+        self.call(request, |request| {
+            let mut watch = watch.clone();
+            async move { watch.watch(request).await }
+        })
+        .await
+    }
+
+    /// Write a large number of relationships at once, streamed in batches.
+    ///
+    /// For maximum performance, send relationships in as close to sort order
+    /// as possible: resource type, resource ID, relation, subject type,
+    /// subject ID and subject relation.
+    ///
+    /// The stream cannot be replayed, so the request is never retried.
+    pub async fn bulk_import_relationships<S>(
+        &self,
+        requests: S,
+    ) -> Result<BulkImportRelationshipsResponse>
+    where
+        S: Stream<Item = BulkImportRelationshipsRequest> + Send + 'static,
+    {
+        let request = self.request(requests).await?;
+        let response = self
+            .experimental
+            .clone()
+            .bulk_import_relationships(request)
+            .await?
+            .into_inner();
 
         Ok(response)
     }
+
+    /// Import relationships from a stream, in messages of
+    /// [`DEFAULT_BULK_IMPORT_CHUNK_SIZE`] relationships, returning the number
+    /// of relationships loaded.
+    ///
+    /// The import is atomic: it fails if any of the relationships already
+    /// exists.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_grpc::authzed::api::v1::Relationship;
+    /// #
+    /// # async fn import(client: SpicedbClient, relationships: Vec<Relationship>) {
+    /// let num_loaded = client
+    ///     .bulk_import(tokio_stream::iter(relationships))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn bulk_import<S>(&self, relationships: S) -> Result<u64>
+    where
+        S: Stream<Item = Relationship> + Send + 'static,
+    {
+        self.bulk_import_with_progress(relationships, DEFAULT_BULK_IMPORT_CHUNK_SIZE, |_| {})
+            .await
+    }
+
+    /// Import relationships from an iterator, see
+    /// [`bulk_import`](Self::bulk_import).
+    pub async fn bulk_import_iter<I>(&self, relationships: I) -> Result<u64>
+    where
+        I: IntoIterator<Item = Relationship>,
+        I::IntoIter: Send + 'static,
+    {
+        self.bulk_import(tokio_stream::iter(relationships)).await
+    }
+
+    /// Import relationships from a stream, in messages of `chunk_size`
+    /// relationships, calling `progress` with the number of relationships
+    /// sent so far after each message.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_grpc::authzed::api::v1::Relationship;
+    /// #
+    /// # async fn import(client: SpicedbClient, relationships: Vec<Relationship>) {
+    /// let num_loaded = client
+    ///     .bulk_import_with_progress(
+    ///         tokio_stream::iter(relationships),
+    ///         5_000,
+    ///         |sent| println!("sent {sent} relationships"),
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn bulk_import_with_progress<S, F>(
+        &self,
+        relationships: S,
+        chunk_size: usize,
+        progress: F,
+    ) -> Result<u64>
+    where
+        S: Stream<Item = Relationship> + Send + 'static,
+        F: FnMut(u64) + Send + 'static,
+    {
+        let requests = ImportChunks::new(relationships, chunk_size, progress);
+        let response = self.bulk_import_relationships(requests).await?;
+
+        Ok(response.num_loaded)
+    }
+
+    /// Export relationships as a stream, resuming from the last cursor after
+    /// transient failures.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_grpc::authzed::api::v1::BulkExportRelationshipsRequest;
+    /// # use tokio_stream::StreamExt;
+    /// #
+    /// # async fn export(client: SpicedbClient) {
+    /// let mut export = client.bulk_export(BulkExportRelationshipsRequest::default());
+    /// while let Some(relationship) = export.next().await {
+    ///     let relationship = relationship.unwrap();
+    ///     // Save `export.cursor()` to resume the export after a crash.
+    /// }
+    /// # }
+    /// ```
+    pub fn bulk_export(&self, request: BulkExportRelationshipsRequest) -> BulkExport
+    where
+        T: Send + Sync + 'static,
+        T::Future: Send,
+    {
+        let client = self.clone();
+        let cursor = request.optional_cursor.clone();
+        let connect: ExportConnect = Box::new(move |cursor| {
+            let client = client.clone();
+            let request = BulkExportRelationshipsRequest {
+                optional_cursor: cursor,
+                ..request.clone()
+            };
+            Box::pin(async move {
+                let pages = client.bulk_export_relationships(request).await?;
+                Ok(Box::pin(pages) as ExportPages)
+            })
+        });
+        BulkExport::new(cursor, self.retry_policy.clone(), connect)
+    }
+
+    /// Export relationships in an order determined by the server. The export
+    /// can be resumed from the cursor of the last received batch.
+    pub async fn bulk_export_relationships(
+        &self,
+        request: BulkExportRelationshipsRequest,
+    ) -> Result<Streaming<BulkExportRelationshipsResponse>> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move { experimental.bulk_export_relationships(request).await }
+        })
+        .await
+    }
+
+    /// Reflect the definitions and caveats of the schema stored in SpiceDB.
+    pub async fn experimental_reflect_schema(
+        &self,
+        request: ExperimentalReflectSchemaRequest,
+    ) -> Result<ExperimentalReflectSchemaResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move { experimental.experimental_reflect_schema(request).await }
+        })
+        .await
+    }
+
+    /// Reflect the schema stored in SpiceDB as a typed [`Schema`].
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_client::builder::{ExpSchemaFilterBuilder, ExperimentalReflectSchemaRequestBuilder};
+    /// # use spicedb_grpc::authzed::api::v1::ExperimentalReflectSchemaRequest;
+    /// #
+    /// # async fn reflect(client: SpicedbClient) {
+    /// let mut request = ExperimentalReflectSchemaRequest::new();
+    /// request.add_filter().definition_name_prefix("document");
+    ///
+    /// let schema = client.reflect_schema(request).await.unwrap();
+    /// let viewer = schema
+    ///     .definition("document")
+    ///     .and_then(|document| document.relation("viewer"));
+    /// # }
+    /// ```
+    pub async fn reflect_schema(
+        &self,
+        request: ExperimentalReflectSchemaRequest,
+    ) -> Result<Schema> {
+        let response = self.experimental_reflect_schema(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Compare `schema` to the schema stored in SpiceDB, returning the typed
+    /// changes it would make.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// #
+    /// # async fn check(client: SpicedbClient, schema: &str) {
+    /// let diff = client.diff_schema(schema).await.unwrap();
+    /// if diff.is_breaking() {
+    ///     panic!("refusing to deploy a breaking schema change");
+    /// }
+    /// client.write_schema(schema).await.unwrap();
+    /// # }
+    /// ```
+    pub async fn diff_schema(&self, schema: impl ToString) -> Result<SchemaDiff> {
+        let request = ExperimentalDiffSchemaRequest {
+            consistency: None,
+            comparison_schema: schema.to_string(),
+        };
+        let response = self.experimental_diff_schema(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Return the permissions computed from a relation.
+    ///
+    /// For example, with `permission view = viewer + editor`, the computable
+    /// permissions of `viewer` include `view`.
+    pub async fn experimental_computable_permissions(
+        &self,
+        request: ExperimentalComputablePermissionsRequest,
+    ) -> Result<ExperimentalComputablePermissionsResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move {
+                experimental
+                    .experimental_computable_permissions(request)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Return the relations and permissions used to compute a permission,
+    /// recursively. This is the inverse of
+    /// [`experimental_computable_permissions`](Self::experimental_computable_permissions).
+    pub async fn experimental_dependent_relations(
+        &self,
+        request: ExperimentalDependentRelationsRequest,
+    ) -> Result<ExperimentalDependentRelationsResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move { experimental.experimental_dependent_relations(request).await }
+        })
+        .await
+    }
+
+    /// Return the permissions that may be granted by the relation or
+    /// permission of the request, across all definitions unless filtered.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_client::builder::ExperimentalComputablePermissionsRequestBuilder;
+    /// # use spicedb_client::types::FullyConsistent;
+    /// # use spicedb_grpc::authzed::api::v1::ExperimentalComputablePermissionsRequest;
+    /// #
+    /// # async fn granted_by_editor(client: SpicedbClient) {
+    /// let mut request = ExperimentalComputablePermissionsRequest::new("document", "editor");
+    /// request.consistency(FullyConsistent(true));
+    ///
+    /// let permissions = client.computable_permissions(request).await.unwrap();
+    /// for permission in &permissions {
+    ///     println!("{}#{}", permission.definition_name, permission.relation_name);
+    /// }
+    /// # }
+    /// ```
+    pub async fn computable_permissions(
+        &self,
+        request: ExperimentalComputablePermissionsRequest,
+    ) -> Result<HashSet<ExpRelationReference>> {
+        let response = self.experimental_computable_permissions(request).await?;
+
+        Ok(response.permissions.into_iter().collect())
+    }
+
+    /// Return the relations and permissions that the permission of the
+    /// request depends on.
+    pub async fn dependent_relations(
+        &self,
+        request: ExperimentalDependentRelationsRequest,
+    ) -> Result<HashSet<ExpRelationReference>> {
+        let response = self.experimental_dependent_relations(request).await?;
+
+        Ok(response.relations.into_iter().collect())
+    }
+
+    /// Return the differences between the given schema and the schema stored
+    /// in SpiceDB.
+    pub async fn experimental_diff_schema(
+        &self,
+        request: ExperimentalDiffSchemaRequest,
+    ) -> Result<ExperimentalDiffSchemaResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move { experimental.experimental_diff_schema(request).await }
+        })
+        .await
+    }
+
+    /// Register the counter `name` for the relationships matching `filter`.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_client::builder::RelationshipFilterBuilder;
+    /// # use spicedb_grpc::authzed::api::v1::RelationshipFilter;
+    /// #
+    /// # async fn count_members(client: SpicedbClient) {
+    /// let mut filter = RelationshipFilter::new();
+    /// filter.resource_type("group").relation("member");
+    /// client
+    ///     .register_relationship_counter("group_members", filter)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// let count = client.count_relationships("group_members").await.unwrap();
+    /// # }
+    /// ```
+    pub async fn register_relationship_counter(
+        &self,
+        name: impl ToString,
+        filter: RelationshipFilter,
+    ) -> Result<()> {
+        let mut request = ExperimentalRegisterRelationshipCounterRequest::new(name);
+        *request.relationship_filter() = filter;
+        self.experimental_register_relationship_counter(request)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Count the relationships matching the registered counter `name`.
+    pub async fn count_relationships(&self, name: impl ToString) -> Result<RelationshipCount> {
+        let request = ExperimentalCountRelationshipsRequest::new(name);
+        let response = self.experimental_count_relationships(request).await?;
+
+        Ok(response.into())
+    }
+
+    /// Unregister the counter `name`.
+    pub async fn unregister_relationship_counter(&self, name: impl ToString) -> Result<()> {
+        let request = ExperimentalUnregisterRelationshipCounterRequest::new(name);
+        self.experimental_unregister_relationship_counter(request)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Register a named filter for counting relationships. A filter must be
+    /// registered before its relationships can be counted.
+    pub async fn experimental_register_relationship_counter(
+        &self,
+        request: ExperimentalRegisterRelationshipCounterRequest,
+    ) -> Result<ExperimentalRegisterRelationshipCounterResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move {
+                experimental
+                    .experimental_register_relationship_counter(request)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Count the relationships matching a registered filter.
+    pub async fn experimental_count_relationships(
+        &self,
+        request: ExperimentalCountRelationshipsRequest,
+    ) -> Result<ExperimentalCountRelationshipsResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move { experimental.experimental_count_relationships(request).await }
+        })
+        .await
+    }
+
+    /// Unregister a filter for counting relationships.
+    pub async fn experimental_unregister_relationship_counter(
+        &self,
+        request: ExperimentalUnregisterRelationshipCounterRequest,
+    ) -> Result<ExperimentalUnregisterRelationshipCounterResponse> {
+        let experimental = self.experimental.clone();
+        self.call(request, |request| {
+            let mut experimental = experimental.clone();
+            async move {
+                experimental
+                    .experimental_unregister_relationship_counter(request)
+                    .await
+            }
+        })
+        .await
+    }
 }
 
-#[derive(Clone)]
-struct SpicedbMiddleware {
-    preshared_key: Box<MetadataValue<Ascii>>,
+#[cfg(feature = "alpha")]
+impl<T> SpicedbClient<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Watch the updates to `permission` of resources, rather than to the
+    /// relationships it is computed from.
+    ///
+    /// This API is in alpha and may change or be removed in any SpiceDB
+    /// release.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_grpc::authzed::api::v1alpha1::WatchResourcesRequest;
+    /// # use tokio_stream::StreamExt;
+    /// #
+    /// # async fn invalidate(client: SpicedbClient) {
+    /// let request = WatchResourcesRequest {
+    ///     resource_object_type: "document".to_string(),
+    ///     permission: "view".to_string(),
+    ///     subject_object_type: "user".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let mut updates = client.watch_resources(request).await.unwrap();
+    /// while let Some(update) = updates.next().await {
+    ///     let update = update.unwrap();
+    ///     // Invalidate the cached permission of `update.subject` on `update.resource`.
+    /// }
+    /// # }
+    /// ```
+    pub async fn watch_resources(
+        &self,
+        request: WatchResourcesRequest,
+    ) -> Result<PermissionUpdates> {
+        let responses = self.watch_resources_responses(request).await?;
+
+        Ok(PermissionUpdates::new(
+            Box::pin(responses) as WatchResourcesResponses
+        ))
+    }
+
+    /// Watch the updates to a permission of resources, as sent by the server.
+    pub async fn watch_resources_responses(
+        &self,
+        request: WatchResourcesRequest,
+    ) -> Result<Streaming<WatchResourcesResponse>> {
+        let watch_resources = self.watch_resources.clone();
+        self.call(request, |request| {
+            let mut watch_resources = watch_resources.clone();
+            async move { watch_resources.watch_resources(request).await }
+        })
+        .await
+    }
 }
 
-impl Interceptor for SpicedbMiddleware {
-    fn call(&mut self, mut request: Request<()>) -> Result<tonic::Request<()>, Status> {
-        request
-            .metadata_mut()
-            .insert("authorization", (*self.preshared_key).clone());
-        Ok(request)
+#[cfg(feature = "materialize")]
+impl<T> SpicedbClient<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Watch the changes to the permissions of subjects on resources, as
+    /// computed by the Materialize API.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use spicedb_client::materialize::PermissionEvent;
+    /// # use spicedb_grpc::authzed::api::materialize::v0::{WatchPermissionsRequest, WatchedPermission};
+    /// # use tokio_stream::StreamExt;
+    /// #
+    /// # async fn watch(client: SpicedbClient) {
+    /// let request = WatchPermissionsRequest {
+    ///     permissions: vec![WatchedPermission {
+    ///         resource_type: "document".to_string(),
+    ///         permission: "view".to_string(),
+    ///         subject_type: "user".to_string(),
+    ///         optional_subject_relation: String::new(),
+    ///     }],
+    ///     optional_starting_after: None,
+    /// };
+    /// let mut events = client.watch_permissions(request).await.unwrap();
+    /// while let Some(event) = events.next().await {
+    ///     match event.unwrap() {
+    ///         PermissionEvent::Change(change) => println!("{change:?}"),
+    ///         PermissionEvent::Completed(revision) => println!("up to {}", revision.token),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn watch_permissions(
+        &self,
+        request: WatchPermissionsRequest,
+    ) -> Result<PermissionEvents> {
+        let watch_permissions = self.watch_permissions.clone();
+        let responses = self
+            .call(request, |request| {
+                let mut watch_permissions = watch_permissions.clone();
+                async move { watch_permissions.watch_permissions(request).await }
+            })
+            .await?;
+
+        Ok(Events::new(responses))
+    }
+
+    /// Watch the changes to the permission sets computed by the Materialize
+    /// API.
+    pub async fn watch_permission_sets(
+        &self,
+        request: WatchPermissionSetsRequest,
+    ) -> Result<PermissionSetEvents> {
+        let watch_permission_sets = self.watch_permission_sets.clone();
+        let responses = self
+            .call(request, |request| {
+                let mut watch_permission_sets = watch_permission_sets.clone();
+                async move { watch_permission_sets.watch_permission_sets(request).await }
+            })
+            .await?;
+
+        Ok(Events::new(responses))
+    }
+
+    /// Look up all the permission sets, in pages of `limit` changes, starting
+    /// after `cursor` if set.
+    ///
+    /// ```rust
+    /// # use spicedb_client::SpicedbClient;
+    /// # use tokio_stream::StreamExt;
+    /// #
+    /// # async fn index(client: SpicedbClient) {
+    /// let mut lookup = client.lookup_permission_sets(1_000, None);
+    /// while let Some(change) = lookup.next().await {
+    ///     let change = change.unwrap();
+    ///     // Save `lookup.cursor()` to resume the lookup after a crash.
+    /// }
+    /// # }
+    /// ```
+    pub fn lookup_permission_sets(
+        &self,
+        limit: u32,
+        cursor: Option<MaterializeCursor>,
+    ) -> LookupPermissionSets
+    where
+        T: Send + Sync + 'static,
+        T::Future: Send,
+    {
+        let client = self.clone();
+        let connect: LookupConnect = Box::new(move |cursor| {
+            let client = client.clone();
+            let request = LookupPermissionSetsRequest {
+                limit,
+                optional_starting_after_cursor: cursor,
+            };
+            Box::pin(async move {
+                let page = client.lookup_permission_sets_page(request).await?;
+                Ok(Box::pin(page) as LookupPage)
+            })
+        });
+        LookupPermissionSets::new(limit, cursor, self.retry_policy.clone(), connect)
+    }
+
+    /// Look up a page of permission sets. The lookup can be continued from
+    /// the cursor of the last received change.
+    pub async fn lookup_permission_sets_page(
+        &self,
+        request: LookupPermissionSetsRequest,
+    ) -> Result<Streaming<LookupPermissionSetsResponse>> {
+        let watch_permission_sets = self.watch_permission_sets.clone();
+        self.call(request, |request| {
+            let mut watch_permission_sets = watch_permission_sets.clone();
+            async move { watch_permission_sets.lookup_permission_sets(request).await }
+        })
+        .await
     }
 }
 
@@ -248,9 +1080,85 @@ mod test {
     use tokio::test;
 
     use crate::reader::*;
+    use crate::schema::schema_semantically_equal;
 
     use super::*;
 
+    type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+    /// Serve routes without any service on `incoming` in the background,
+    /// behind `interceptor`. Requests that the interceptor lets through fail
+    /// with `UNIMPLEMENTED`.
+    fn serve<F, I, IO, IE>(server: tonic::transport::Server, interceptor: F, incoming: I)
+    where
+        F: tonic::service::Interceptor + Clone + Send + Sync + 'static,
+        I: tokio_stream::Stream<Item = Result<IO, IE>> + Send + 'static,
+        IO: tokio::io::AsyncRead
+            + tokio::io::AsyncWrite
+            + tonic::transport::server::Connected
+            + Unpin
+            + Send
+            + 'static,
+        IO::ConnectInfo: Clone + Send + Sync + 'static,
+        IE: Into<BoxError> + Send + 'static,
+    {
+        let server = server
+            .layer(tonic::service::interceptor(interceptor))
+            .add_routes(tonic::service::Routes::default())
+            .serve_with_incoming(incoming);
+        tokio::spawn(server);
+    }
+
+    /// Serve routes without any service behind `interceptor` on a random
+    /// local port, returning the URL of the server.
+    async fn test_server<F>(interceptor: F) -> String
+    where
+        F: tonic::service::Interceptor + Clone + Send + Sync + 'static,
+    {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+        serve(tonic::transport::Server::builder(), interceptor, incoming);
+        url
+    }
+
+    /// A client sending requests in-process to routes without any service,
+    /// behind `interceptor`.
+    fn in_process_client<F>(
+        interceptor: F,
+    ) -> SpicedbClient<tonic::service::interceptor::InterceptedService<tonic::service::Routes, F>>
+    where
+        F: tonic::service::Interceptor + Clone,
+    {
+        let transport = tonic::service::interceptor::InterceptedService::new(
+            tonic::service::Routes::default(),
+            interceptor,
+        );
+        SpicedbClient::with_origin(transport, Uri::from_static("http://spicedb"))
+    }
+
+    /// URL of a local port with nothing listening on it.
+    async fn closed_url() -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    /// Reply as SpiceDB does without a schema.
+    fn no_schema(_: Request<()>) -> Result<Request<()>, Status> {
+        Err(Status::not_found("No schema has been defined"))
+    }
+
+    /// Echo the authorization header back in the error message.
+    fn echo_authorization(request: Request<()>) -> Result<Request<()>, Status> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        Err(Status::unauthenticated(authorization))
+    }
+
     #[test]
     pub async fn test_spicedb() {
         let spicedb_url =
@@ -259,7 +1167,7 @@ mod test {
         let preshared_key =
             env::var("SPICEDB_PRESHARED_KEY").unwrap_or_else(|_| "spicedb".to_string());
 
-        let mut client = SpicedbClient::from_url_and_preshared_key(spicedb_url, preshared_key)
+        let client = SpicedbClient::from_url_and_preshared_key(spicedb_url, preshared_key)
             .await
             .unwrap();
 
@@ -281,13 +1189,487 @@ definition document {
 
         // Read schema
         let response = client.read_schema().await.unwrap();
+        assert!(schema_semantically_equal(response.schema_text(), schema).unwrap());
+        assert!(response.read_at().is_some());
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    pub async fn test_tls() {
+        use rcgen::{BasicConstraints, CertificateParams, ExtendedKeyUsagePurpose, IsCa, KeyPair};
+        use tokio::net::TcpListener;
+        use tokio_stream::wrappers::TcpListenerStream;
+        use tonic::transport::{Server, ServerTlsConfig};
+
+        use crate::result::Error;
+        use crate::{Certificate, ClientTlsConfig, Identity};
+
+        let ca_key = KeyPair::generate().unwrap();
+        let mut ca_params = CertificateParams::new(Vec::new()).unwrap();
+        ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        let ca_cert = ca_params.self_signed(&ca_key).unwrap();
+
+        let server_key = KeyPair::generate().unwrap();
+        let mut server_params = CertificateParams::new(vec!["spicedb.test".to_string()]).unwrap();
+        server_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ServerAuth];
+        let server_cert = server_params
+            .signed_by(&server_key, &ca_cert, &ca_key)
+            .unwrap();
+
+        let client_key = KeyPair::generate().unwrap();
+        let mut client_params = CertificateParams::new(vec!["client".to_string()]).unwrap();
+        client_params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+        let client_cert = client_params
+            .signed_by(&client_key, &ca_cert, &ca_key)
+            .unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("https://{}", listener.local_addr().unwrap());
+
+        let server_tls = ServerTlsConfig::new()
+            .identity(Identity::from_pem(
+                server_cert.pem(),
+                server_key.serialize_pem(),
+            ))
+            .client_ca_root(Certificate::from_pem(ca_cert.pem()));
+
+        serve(
+            Server::builder().tls_config(server_tls).unwrap(),
+            Ok,
+            TcpListenerStream::new(listener),
+        );
+
+        // Mutual TLS with a custom root CA and a domain override succeeds. The
+        // server has no services, so a successful round trip is UNIMPLEMENTED.
+        let tls = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(ca_cert.pem()))
+            .identity(Identity::from_pem(
+                client_cert.pem(),
+                client_key.serialize_pem(),
+            ))
+            .domain_name("spicedb.test");
+
+        let client =
+            SpicedbClient::from_url_and_preshared_key_with_tls(url.clone(), "spicedb", tls)
+                .await
+                .unwrap();
+
+        match client.write_schema("definition user {}").await {
+            Err(Error::TonicStatus(status)) => assert_eq!(status.code(), Code::Unimplemented),
+            other => panic!("unexpected response: {other:?}"),
+        }
+
+        // Without a client identity the server rejects the connection.
+        let tls = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(ca_cert.pem()))
+            .domain_name("spicedb.test");
+
+        let result = async {
+            let client =
+                SpicedbClient::from_url_and_preshared_key_with_tls(url.clone(), "spicedb", tls)
+                    .await?;
+            client.write_schema("definition user {}").await
+        }
+        .await;
+
+        match result {
+            Err(Error::TonicStatus(status)) => assert_ne!(status.code(), Code::Unimplemented),
+            Err(_) => {}
+            Ok(response) => panic!("unexpected response: {response:?}"),
+        }
+    }
+
+    #[test]
+    pub async fn test_connect_lazy() {
+        use std::time::Duration;
+
+        use crate::result::Error;
+
+        let mut builder = SpicedbClient::builder(closed_url().await);
+        builder
+            .preshared_key("spicedb")
+            .connect_timeout(Duration::from_secs(1))
+            .timeout(Duration::from_secs(1))
+            .http2_keep_alive_interval(Duration::from_secs(30))
+            .tcp_nodelay(true)
+            .concurrency_limit(16)
+            .max_decoding_message_size(1024 * 1024)
+            .user_agent("spicedb-client-test");
+
+        assert!(builder.connect().await.is_err());
+
+        let client = builder.connect_lazy().unwrap();
+        match client.write_schema("definition user {}").await {
+            Err(Error::TonicStatus(status)) => assert_eq!(status.code(), Code::Unavailable),
+            other => panic!("unexpected response: {other:?}"),
+        }
+    }
+
+    #[test]
+    pub async fn test_credentials() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use crate::result::Error;
+
+        let url = test_server(echo_authorization).await;
+
+        let counter = Arc::new(AtomicUsize::new(0));
+        let client = SpicedbClient::builder(url)
+            .credentials(move || {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(format!("token-{n}")) }
+            })
+            .connect()
+            .await
+            .unwrap();
+
+        for expected in ["bearer token-0", "bearer token-1"] {
+            match client.write_schema("definition user {}").await {
+                Err(Error::TonicStatus(status)) => assert_eq!(status.message(), expected),
+                other => panic!("unexpected response: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    pub async fn test_read_schema_opt() {
+        let url = test_server(no_schema).await;
+        let client = SpicedbClient::from_url_and_preshared_key(url, "spicedb")
+            .await
+            .unwrap();
+
+        let err = client.read_schema().await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::NotFound));
+
+        assert!(client.read_schema_opt().await.unwrap().is_none());
+    }
+
+    #[test]
+    pub async fn test_retry() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+        use std::time::Duration;
+
+        use crate::builder::WriteRelationshipsRequestBuilder;
+        use crate::retry::RetryPolicy;
+
+        // Fail the first two attempts of every request, the routes then reply
+        // with UNIMPLEMENTED.
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let url = test_server({
+            let attempts = attempts.clone();
+            move |request: Request<()>| {
+                if attempts.fetch_add(1, Ordering::SeqCst) % 3 < 2 {
+                    Err(Status::unavailable("try again"))
+                } else {
+                    Ok(request)
+                }
+            }
+        })
+        .await;
+
+        let mut policy = RetryPolicy::new();
+        policy.initial_backoff(Duration::from_millis(1));
+        let client = SpicedbClient::builder(url)
+            .retry_policy(policy)
+            .connect()
+            .await
+            .unwrap();
+
+        let err = client.read_schema().await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::Unimplemented));
+        assert_eq!(attempts.swap(0, Ordering::SeqCst), 3);
+
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.create_relationship("document", "doc1", "viewer", "user", "user1");
+        let err = client.write_relationships(request).await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::Unavailable));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    pub async fn test_balance() {
+        use std::collections::HashSet;
+        use std::time::Duration;
+
+        use tokio::net::TcpListener;
+
+        use crate::retry::RetryPolicy;
+
+        // Every replica replies with its own name.
+        let mut urls = Vec::new();
+        for name in ["a", "b"] {
+            urls.push(test_server(move |_: Request<()>| Err(Status::not_found(name))).await);
+        }
+
+        let down = closed_url().await;
+
+        // Accepts connections but never replies.
+        let hanging = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let hanging_url = format!("http://{}", hanging.local_addr().unwrap());
+
+        let client = SpicedbClient::builder(urls[0].clone())
+            .add_endpoint(urls[1].clone())
+            .add_endpoint(down.clone())
+            .add_endpoint(hanging_url)
+            .health_check_timeout(Duration::from_millis(200))
+            .retry_policy(RetryPolicy::disabled())
+            .connect()
+            .await
+            .unwrap();
+
+        let mut replicas = HashSet::new();
+        for _ in 0..50 {
+            let err = client.read_schema().await.unwrap_err();
+            assert_eq!(err.code(), Some(Code::NotFound));
+            replicas.insert(err.status().unwrap().message().to_string());
+        }
+        assert_eq!(replicas, HashSet::from(["a".to_string(), "b".to_string()]));
+
+        let err = SpicedbClient::builder(down.clone())
+            .add_endpoint(down)
+            .health_check_timeout(Duration::from_millis(500))
+            .connect()
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(Code::Unavailable));
+    }
+
+    #[test]
+    pub async fn test_shared() {
+        use std::sync::Arc;
+
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+        let url = test_server(no_schema).await;
+        let client = Arc::new(
+            SpicedbClient::from_url_and_preshared_key(url, "spicedb")
+                .await
+                .unwrap(),
+        );
+        assert_send_sync(&client);
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.read_schema_opt().await })
+            })
+            .collect();
+        for task in tasks {
+            assert!(task.await.unwrap().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    pub async fn test_custom_transport() {
+        use crate::credentials::StaticKey;
+
+        let client =
+            in_process_client(echo_authorization).with_credentials(StaticKey::new("spicedb"));
+
+        let err = client.read_schema().await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::Unauthenticated));
+        assert_eq!(err.status().unwrap().message(), "bearer spicedb");
+
+        // The transport can be used directly, without the client's
+        // credentials.
+        let mut schemas = SchemaServiceClient::with_origin(
+            client.transport().clone(),
+            Uri::from_static("http://spicedb"),
+        );
+        let status = schemas.read_schema(ReadSchemaRequest {}).await.unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(status.message(), "");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    pub async fn test_unix_socket() {
+        use tokio::net::UnixListener;
+        use tokio_stream::wrappers::UnixListenerStream;
+        use tonic::transport::Server;
+
+        let path = env::temp_dir().join(format!("spicedb-client-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        serve(
+            Server::builder(),
+            no_schema,
+            UnixListenerStream::new(listener),
+        );
+
+        let client = SpicedbClient::from_unix_socket_and_preshared_key(&path, "spicedb")
+            .await
+            .unwrap();
+        assert!(client.read_schema_opt().await.unwrap().is_none());
+
+        let client = SpicedbClient::builder(format!("unix://{}", path.display()))
+            .connect_lazy()
+            .unwrap();
+        assert!(client.read_schema_opt().await.unwrap().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    pub async fn test_bulk_import_relationships() {
+        use crate::credentials::StaticKey;
+
+        // The streamed request carries the authorization header.
+        let client =
+            in_process_client(echo_authorization).with_credentials(StaticKey::new("spicedb"));
+
+        let requests = tokio_stream::iter([BulkImportRelationshipsRequest {
+            relationships: vec![],
+        }]);
+        let err = client
+            .bulk_import_relationships(requests)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(Code::Unauthenticated));
+        assert_eq!(err.status().unwrap().message(), "bearer spicedb");
+    }
+
+    /// In-process server answering every unary request with `reply`.
+    struct UnaryServer<Req, Res> {
+        reply: fn(Req) -> Res,
+    }
+
+    impl<Req, Res> Clone for UnaryServer<Req, Res> {
+        fn clone(&self) -> Self {
+            Self { reply: self.reply }
+        }
+    }
+
+    impl<Req, Res> UnaryServer<Req, Res>
+    where
+        Req: prost::Message + Default + 'static,
+        Res: prost::Message + 'static,
+    {
+        fn client(reply: fn(Req) -> Res) -> SpicedbClient<Self> {
+            SpicedbClient::with_origin(Self { reply }, Uri::from_static("http://spicedb"))
+        }
+    }
+
+    impl<Req: Send + 'static, Res: Send + 'static> tonic::server::UnaryService<Req>
+        for UnaryServer<Req, Res>
+    {
+        type Response = Res;
+        type Future = std::future::Ready<Result<Response<Res>, Status>>;
+
+        fn call(&mut self, request: Request<Req>) -> Self::Future {
+            std::future::ready(Ok(Response::new((self.reply)(request.into_inner()))))
+        }
+    }
+
+    impl<Req, Res> tower::Service<http::Request<BoxBody>> for UnaryServer<Req, Res>
+    where
+        Req: prost::Message + Default + 'static,
+        Res: prost::Message + 'static,
+    {
+        type Response = http::Response<BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future =
+            std::pin::Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+        fn poll_ready(
+            &mut self,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+            let service = self.clone();
+            Box::pin(async move {
+                let codec = tonic::codec::ProstCodec::<Res, Req>::default();
+                Ok(tonic::server::Grpc::new(codec)
+                    .unary(service, request)
+                    .await)
+            })
+        }
+    }
+
+    #[test]
+    pub async fn test_computable_permissions() {
+        use crate::builder::ExperimentalComputablePermissionsRequestBuilder;
+        use crate::types::FullyConsistent;
+
+        // Reply with the filter as the definition, and whether a consistency
+        // was requested as `is_permission`.
+        let client = UnaryServer::client(|request: ExperimentalComputablePermissionsRequest| {
+            ExperimentalComputablePermissionsResponse {
+                permissions: vec![ExpRelationReference {
+                    definition_name: request.optional_definition_name_filter,
+                    relation_name: format!("{}#{}", request.definition_name, request.relation_name),
+                    is_permission: request.consistency.is_some(),
+                }],
+                read_at: None,
+            }
+        });
+
+        let mut request = ExperimentalComputablePermissionsRequest::new("document", "editor");
+        let permissions = client
+            .computable_permissions(request.clone())
+            .await
+            .unwrap();
         assert_eq!(
-            response
-                .schema_text()
-                .split_whitespace()
-                .collect::<Vec<_>>(),
-            schema.split_whitespace().collect::<Vec<_>>()
+            permissions,
+            HashSet::from([ExpRelationReference {
+                definition_name: String::new(),
+                relation_name: "document#editor".to_string(),
+                is_permission: false,
+            }])
+        );
+
+        request
+            .consistency(FullyConsistent(true))
+            .definition_name_filter("folder");
+        let permissions = client.computable_permissions(request).await.unwrap();
+        assert_eq!(
+            permissions,
+            HashSet::from([ExpRelationReference {
+                definition_name: "folder".to_string(),
+                relation_name: "document#editor".to_string(),
+                is_permission: true,
+            }])
+        );
+    }
+
+    #[test]
+    pub async fn test_dependent_relations() {
+        use crate::builder::ExperimentalDependentRelationsRequestBuilder;
+        use crate::types::FullyConsistent;
+
+        let client = UnaryServer::client(|request: ExperimentalDependentRelationsRequest| {
+            ExperimentalDependentRelationsResponse {
+                relations: vec![ExpRelationReference {
+                    definition_name: request.definition_name,
+                    relation_name: request.permission_name,
+                    is_permission: request.consistency.is_some(),
+                }],
+                read_at: None,
+            }
+        });
+
+        let mut request = ExperimentalDependentRelationsRequest::new("document", "view");
+        let relations = client.dependent_relations(request.clone()).await.unwrap();
+        assert!(relations.iter().all(|relation| !relation.is_permission));
+
+        request.consistency(FullyConsistent(true));
+        let relations = client.dependent_relations(request).await.unwrap();
+        assert_eq!(
+            relations,
+            HashSet::from([ExpRelationReference {
+                definition_name: "document".to_string(),
+                relation_name: "view".to_string(),
+                is_permission: true,
+            }])
         );
-        assert!(response.read_at().is_some());
     }
 }
@@ -0,0 +1,275 @@
+//! Credentials used to authenticate requests to SpiceDB.
+//!
+//! A [`CredentialsProvider`] is consulted before every request, so tokens can
+//! be rotated without recreating the [`SpicedbClient`](crate::SpicedbClient).
+
+use std::{
+    env, fmt,
+    future::{ready, Future},
+    path::PathBuf,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant, SystemTime},
+};
+
+use tokio::fs;
+use tonic::{metadata::MetadataValue, Request};
+
+use crate::result::{Error, Result};
+
+/// Future returned by [`CredentialsProvider::token`].
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>>;
+
+/// Provide the bearer token sent in the `authorization` header of each
+/// request.
+///
+/// Synchronous providers can return an already completed future:
+///
+/// ```rust
+/// # use std::future::ready;
+/// # use spicedb_client::credentials::{CredentialsProvider, TokenFuture};
+/// #
+/// struct Fixed;
+///
+/// impl CredentialsProvider for Fixed {
+///     fn token(&self) -> TokenFuture<'_> {
+///         Box::pin(ready(Ok("spicedb".to_string())))
+///     }
+/// }
+/// ```
+///
+/// Asynchronous providers, such as one fetching short-lived OIDC tokens, can
+/// be written as a closure returning a future:
+///
+/// ```rust
+/// # use spicedb_client::builder::SpicedbClientBuilder;
+/// # use spicedb_client::result::Result;
+/// #
+/// # async fn fetch_oidc_token() -> Result<String> { Ok(String::new()) }
+/// #
+/// # fn create_client() {
+/// let client = SpicedbClientBuilder::new("http://localhost:50051")
+///     .credentials(|| fetch_oidc_token())
+///     .connect_lazy()
+///     .unwrap();
+/// # }
+/// ```
+pub trait CredentialsProvider: Send + Sync + 'static {
+    fn token(&self) -> TokenFuture<'_>;
+}
+
+impl<F, Fut> CredentialsProvider for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<String>> + Send + 'static,
+{
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(self())
+    }
+}
+
+/// A fixed preshared key.
+#[derive(Clone)]
+pub struct StaticKey {
+    key: String,
+}
+
+impl StaticKey {
+    pub fn new(key: impl ToString) -> Self {
+        Self {
+            key: key.to_string(),
+        }
+    }
+}
+
+impl CredentialsProvider for StaticKey {
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(ready(Ok(self.key.clone())))
+    }
+}
+
+/// The key is not printed.
+impl fmt::Debug for StaticKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticKey")
+            .field("key", &format_args!(".."))
+            .finish()
+    }
+}
+
+/// A key read from an environment variable on every request.
+#[derive(Clone, Debug)]
+pub struct EnvVar {
+    name: String,
+}
+
+impl EnvVar {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl CredentialsProvider for EnvVar {
+    fn token(&self) -> TokenFuture<'_> {
+        let token = env::var(&self.name).map_err(|err| Error::Credentials(Box::new(err)));
+        Box::pin(ready(token))
+    }
+}
+
+/// Minimum interval between checks of the modification time of a [`KeyFile`].
+const KEY_FILE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A key read from a file, such as a mounted Kubernetes secret.
+///
+/// The file is re-read whenever its modification time changes, which is
+/// checked at most once per second. Leading and trailing whitespace is
+/// ignored.
+#[derive(Debug)]
+pub struct KeyFile {
+    path: PathBuf,
+    cached: Mutex<Option<CachedKey>>,
+}
+
+#[derive(Debug)]
+struct CachedKey {
+    key: String,
+    modified: SystemTime,
+    checked_at: Instant,
+}
+
+impl KeyFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cached: Mutex::new(None),
+        }
+    }
+
+    fn cached(&self) -> MutexGuard<'_, Option<CachedKey>> {
+        self.cached.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    async fn read(&self) -> Result<String> {
+        if let Some(cached) = self.cached().as_ref() {
+            if cached.checked_at.elapsed() < KEY_FILE_CHECK_INTERVAL {
+                return Ok(cached.key.clone());
+            }
+        }
+
+        let modified = fs::metadata(&self.path)
+            .await
+            .and_then(|metadata| metadata.modified())
+            .map_err(|err| Error::Credentials(Box::new(err)))?;
+        if let Some(cached) = self.cached().as_mut() {
+            if cached.modified == modified {
+                cached.checked_at = Instant::now();
+                return Ok(cached.key.clone());
+            }
+        }
+
+        let key = fs::read_to_string(&self.path)
+            .await
+            .map_err(|err| Error::Credentials(Box::new(err)))?
+            .trim()
+            .to_string();
+        *self.cached() = Some(CachedKey {
+            key: key.clone(),
+            modified,
+            checked_at: Instant::now(),
+        });
+        Ok(key)
+    }
+}
+
+impl CredentialsProvider for KeyFile {
+    fn token(&self) -> TokenFuture<'_> {
+        Box::pin(self.read())
+    }
+}
+
+/// Shared handle to the provider used by a client.
+#[derive(Clone)]
+pub(crate) struct Credentials(Arc<dyn CredentialsProvider>);
+
+impl Credentials {
+    pub(crate) fn new(provider: impl CredentialsProvider) -> Self {
+        Self(Arc::new(provider))
+    }
+
+    /// Add the `authorization` header to a request.
+    pub(crate) async fn authorize<T>(&self, request: &mut Request<T>) -> Result<()> {
+        let token = self.0.token().await?;
+        let value: MetadataValue<_> = format!("bearer {token}").parse()?;
+        request.metadata_mut().insert("authorization", value);
+        Ok(())
+    }
+}
+
+/// Wrap a message in a [`Request`], authorized with `credentials` if set.
+pub(crate) async fn authorized_request<M>(
+    credentials: Option<&Credentials>,
+    message: M,
+) -> Result<Request<M>> {
+    let mut request = Request::new(message);
+    if let Some(credentials) = credentials {
+        credentials.authorize(&mut request).await?;
+    }
+    Ok(request)
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Credentials(..)")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs};
+
+    use tokio::test;
+
+    use super::*;
+
+    #[test]
+    pub async fn test_static_key() {
+        let provider = StaticKey::new("spicedb");
+        assert_eq!(provider.token().await.unwrap(), "spicedb");
+        assert_eq!(format!("{provider:?}"), "StaticKey { key: .. }");
+    }
+
+    #[test]
+    pub async fn test_key_file() {
+        let path = env::temp_dir().join(format!("spicedb-client-key-{}", std::process::id()));
+        fs::write(&path, "first\n").unwrap();
+
+        let provider = KeyFile::new(&path);
+        assert_eq!(provider.token().await.unwrap(), "first");
+
+        let expire = || {
+            let mut cached = provider.cached();
+            cached.as_mut().unwrap().checked_at -= KEY_FILE_CHECK_INTERVAL;
+        };
+
+        // Bump the modification time explicitly, filesystem timestamps can be
+        // too coarse to observe two writes in quick succession.
+        fs::write(&path, "second").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        // The file is not checked again within a second.
+        assert_eq!(provider.token().await.unwrap(), "first");
+        expire();
+        assert_eq!(provider.token().await.unwrap(), "second");
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(provider.token().await.unwrap(), "second");
+        expire();
+        assert!(provider.token().await.is_err());
+    }
+}
@@ -0,0 +1,444 @@
+//! Client for the developer service of a SpiceDB server, which validates and
+//! formats schemas without writing them.
+//!
+//! The service is served by `spicedb serve-devtools`, which backs the SpiceDB
+//! playground, and can be used by tools such as pre-commit hooks.
+
+use bytes::Bytes;
+use http::Uri;
+use spicedb_grpc::authzed::api::v0::{
+    developer_service_client::DeveloperServiceClient, user::UserOneof, DeveloperError,
+    EditCheckRequest, EditCheckResponse, FormatSchemaRequest, ObjectAndRelation, RelationTuple,
+    UpgradeSchemaRequest, User, ValidateRequest, ValidateResponse,
+};
+use spicedb_grpc::authzed::api::v1::Relationship;
+use tonic::{
+    body::BoxBody,
+    client::GrpcService,
+    codegen::{Body, StdError},
+    transport::{Channel, Endpoint},
+};
+
+use crate::credentials::{Credentials, CredentialsProvider};
+use crate::result::{Error, Result};
+use crate::retry::{call_with_retry, RetryPolicy};
+
+/// Relation of a subject that is an object rather than a userset.
+const ELLIPSIS: &str = "...";
+
+/// Client for the developer service.
+///
+/// ```rust
+/// # use spicedb_client::builder::ValidateRequestBuilder;
+/// # use spicedb_client::developer::DeveloperClient;
+/// # use spicedb_grpc::authzed::api::v0::ValidateRequest;
+/// #
+/// # async fn check(schema: &str) {
+/// let client = DeveloperClient::connect("http://localhost:50051").await.unwrap();
+///
+/// let schema = client.format_schema(schema).await.unwrap();
+/// let validation = client.validate(ValidateRequest::new(&schema)).await.unwrap();
+/// for error in validation.errors() {
+///     eprintln!("{}:{}: {}", error.line, error.column, error.message);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeveloperClient<T = Channel> {
+    credentials: Option<Credentials>,
+    retry_policy: RetryPolicy,
+    developer: DeveloperServiceClient<T>,
+}
+
+impl DeveloperClient {
+    /// Connect to the developer service at `url`.
+    pub async fn connect(url: impl Into<Bytes>) -> Result<Self> {
+        let channel = Endpoint::from_shared(url)?.connect().await?;
+        Ok(Self::new(channel))
+    }
+
+    /// Create the client without connecting. The connection is established
+    /// on the first request.
+    pub fn connect_lazy(url: impl Into<Bytes>) -> Result<Self> {
+        let channel = Endpoint::from_shared(url)?.connect_lazy();
+        Ok(Self::new(channel))
+    }
+}
+
+impl<T> DeveloperClient<T>
+where
+    T: GrpcService<BoxBody> + Clone,
+    T::Error: Into<StdError>,
+    T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+    <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+{
+    /// Create a new [`DeveloperClient`] sending requests over `transport`,
+    /// see [`SpicedbClient::new`](crate::SpicedbClient::new).
+    pub fn new(transport: T) -> Self {
+        Self {
+            credentials: None,
+            retry_policy: RetryPolicy::default(),
+            developer: DeveloperServiceClient::new(transport),
+        }
+    }
+
+    /// Create a new [`DeveloperClient`] sending requests over `transport`,
+    /// with `origin` as the scheme and authority of the request URIs.
+    pub fn with_origin(transport: T, origin: Uri) -> Self {
+        Self {
+            credentials: None,
+            retry_policy: RetryPolicy::default(),
+            developer: DeveloperServiceClient::with_origin(transport, origin),
+        }
+    }
+
+    /// Authenticate every request with a token from `provider`.
+    pub fn with_credentials(mut self, provider: impl CredentialsProvider) -> Self {
+        self.credentials = Some(Credentials::new(provider));
+        self
+    }
+
+    /// Retry policy for transient failures.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Validate a schema, relationships, expected relations and assertions.
+    pub async fn validate(&self, request: ValidateRequest) -> Result<Validation> {
+        let developer = self.developer.clone();
+        let response = call_with_retry(
+            self.credentials.as_ref(),
+            &self.retry_policy,
+            request,
+            |request| {
+                let mut developer = developer.clone();
+                async move { developer.validate(request).await }
+            },
+        )
+        .await?;
+
+        Ok(response.into())
+    }
+
+    /// Check whether relationships exist, given a schema and relationships.
+    pub async fn edit_check(&self, request: EditCheckRequest) -> Result<EditCheckResponse> {
+        let developer = self.developer.clone();
+        call_with_retry(
+            self.credentials.as_ref(),
+            &self.retry_policy,
+            request,
+            |request| {
+                let mut developer = developer.clone();
+                async move { developer.edit_check(request).await }
+            },
+        )
+        .await
+    }
+
+    /// Format a schema, returning [`Error::Developer`] if it cannot be
+    /// parsed.
+    pub async fn format_schema(&self, schema: impl ToString) -> Result<String> {
+        let request = FormatSchemaRequest {
+            schema: schema.to_string(),
+        };
+        let developer = self.developer.clone();
+        let response = call_with_retry(
+            self.credentials.as_ref(),
+            &self.retry_policy,
+            request,
+            |request| {
+                let mut developer = developer.clone();
+                async move { developer.format_schema(request).await }
+            },
+        )
+        .await?;
+
+        match response.error {
+            Some(error) => Err(Error::Developer(vec![error])),
+            None => Ok(response.formatted_schema),
+        }
+    }
+
+    /// Upgrade legacy namespace configs to a schema, returning
+    /// [`Error::Developer`] if they cannot be upgraded.
+    pub async fn upgrade_schema(
+        &self,
+        namespace_configs: impl IntoIterator<Item = impl ToString>,
+    ) -> Result<String> {
+        let request = UpgradeSchemaRequest {
+            namespace_configs: namespace_configs
+                .into_iter()
+                .map(|config| config.to_string())
+                .collect(),
+        };
+        let developer = self.developer.clone();
+        let response = call_with_retry(
+            self.credentials.as_ref(),
+            &self.retry_policy,
+            request,
+            |request| {
+                let mut developer = developer.clone();
+                async move { developer.upgrade_schema(request).await }
+            },
+        )
+        .await?;
+
+        match response.error {
+            Some(error) => Err(Error::Developer(vec![error])),
+            None => Ok(response.upgraded_schema),
+        }
+    }
+}
+
+/// Result of [`DeveloperClient::validate`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Validation {
+    /// Errors in the schema or relationships, which prevented validation.
+    pub request_errors: Vec<DeveloperError>,
+    /// Failed expected relations and assertions.
+    pub validation_errors: Vec<DeveloperError>,
+    /// The expected relations generated from the relationships, if requested
+    /// with `update_validation_yaml`.
+    pub updated_validation_yaml: String,
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.request_errors.is_empty() && self.validation_errors.is_empty()
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &DeveloperError> {
+        self.request_errors.iter().chain(&self.validation_errors)
+    }
+
+    /// Return [`Error::Developer`] with all the errors if the validation
+    /// failed.
+    pub fn into_result(self) -> Result<Self> {
+        if self.is_valid() {
+            return Ok(self);
+        }
+        let mut errors = self.request_errors;
+        errors.extend(self.validation_errors);
+        Err(Error::Developer(errors))
+    }
+}
+
+impl From<ValidateResponse> for Validation {
+    fn from(response: ValidateResponse) -> Self {
+        Self {
+            request_errors: response.request_errors,
+            validation_errors: response.validation_errors,
+            updated_validation_yaml: response.updated_validation_yaml,
+        }
+    }
+}
+
+/// Convert a relationship to the tuple used by the developer service. Caveats
+/// are not supported and are dropped.
+pub fn relation_tuple(relationship: &Relationship) -> RelationTuple {
+    let resource = relationship.resource.clone().unwrap_or_default();
+    let subject = relationship.subject.clone().unwrap_or_default();
+    let subject_object = subject.object.unwrap_or_default();
+    let subject_relation = match subject.optional_relation.as_str() {
+        "" => ELLIPSIS.to_string(),
+        relation => relation.to_string(),
+    };
+
+    RelationTuple {
+        object_and_relation: Some(ObjectAndRelation {
+            namespace: resource.object_type,
+            object_id: resource.object_id,
+            relation: relationship.relation.clone(),
+        }),
+        user: Some(User {
+            user_oneof: Some(UserOneof::Userset(ObjectAndRelation {
+                namespace: subject_object.object_type,
+                object_id: subject_object.object_id,
+                relation: subject_relation,
+            })),
+        }),
+    }
+}
+
+/// Format developer errors as `line:column: message`, one per line.
+pub(crate) fn format_errors(errors: &[DeveloperError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("{}:{}: {}", error.line, error.column, error.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use spicedb_grpc::authzed::api::v1::{ObjectReference, SubjectReference};
+
+    use super::*;
+
+    #[tokio::test]
+    pub async fn test_developer_client() {
+        use spicedb_grpc::authzed::api::v0::{
+            developer_service_server::{DeveloperService, DeveloperServiceServer},
+            FormatSchemaResponse, LookupShareRequest, LookupShareResponse, ShareRequest,
+            ShareResponse, UpgradeSchemaResponse,
+        };
+        use tonic::{Request, Response, Status};
+
+        use crate::builder::ValidateRequestBuilder;
+        use crate::credentials::StaticKey;
+
+        /// Formats schemas by trimming them, and rejects empty ones.
+        struct Developer;
+
+        #[tonic::async_trait]
+        impl DeveloperService for Developer {
+            async fn edit_check(
+                &self,
+                _: Request<EditCheckRequest>,
+            ) -> Result<Response<EditCheckResponse>, Status> {
+                Err(Status::unimplemented("edit_check"))
+            }
+
+            async fn validate(
+                &self,
+                request: Request<ValidateRequest>,
+            ) -> Result<Response<ValidateResponse>, Status> {
+                let context = request.into_inner().context.unwrap_or_default();
+                let request_errors = match context.schema.trim() {
+                    "" => vec![DeveloperError {
+                        message: "empty schema".to_string(),
+                        line: 1,
+                        column: 1,
+                        ..Default::default()
+                    }],
+                    _ => vec![],
+                };
+                Ok(Response::new(ValidateResponse {
+                    request_errors,
+                    ..Default::default()
+                }))
+            }
+
+            async fn share(
+                &self,
+                _: Request<ShareRequest>,
+            ) -> Result<Response<ShareResponse>, Status> {
+                Err(Status::unimplemented("share"))
+            }
+
+            async fn lookup_shared(
+                &self,
+                _: Request<LookupShareRequest>,
+            ) -> Result<Response<LookupShareResponse>, Status> {
+                Err(Status::unimplemented("lookup_shared"))
+            }
+
+            async fn upgrade_schema(
+                &self,
+                _: Request<UpgradeSchemaRequest>,
+            ) -> Result<Response<UpgradeSchemaResponse>, Status> {
+                Err(Status::unimplemented("upgrade_schema"))
+            }
+
+            async fn format_schema(
+                &self,
+                request: Request<FormatSchemaRequest>,
+            ) -> Result<Response<FormatSchemaResponse>, Status> {
+                if request.metadata().get("authorization").is_none() {
+                    return Err(Status::unauthenticated("missing token"));
+                }
+                let schema = request.into_inner().schema;
+                let error = schema.trim().is_empty().then(|| DeveloperError {
+                    message: "empty schema".to_string(),
+                    line: 1,
+                    column: 1,
+                    ..Default::default()
+                });
+                Ok(Response::new(FormatSchemaResponse {
+                    error,
+                    formatted_schema: schema.trim().to_string(),
+                }))
+            }
+        }
+
+        let server = DeveloperServiceServer::new(Developer);
+        let client = DeveloperClient::with_origin(server, Uri::from_static("http://spicedb"));
+
+        let err = client
+            .format_schema("definition user {}")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(tonic::Code::Unauthenticated));
+
+        let client = client.with_credentials(StaticKey::new("spicedb"));
+        let schema = client
+            .format_schema("  definition user {}\n")
+            .await
+            .unwrap();
+        assert_eq!(schema, "definition user {}");
+        let err = client.format_schema(" ").await.unwrap_err();
+        assert_eq!(err.to_string(), "1:1: empty schema");
+
+        let validation = client
+            .validate(ValidateRequest::new(&schema))
+            .await
+            .unwrap();
+        assert!(validation.is_valid());
+        let validation = client.validate(ValidateRequest::new("")).await.unwrap();
+        assert!(validation.into_result().is_err());
+    }
+
+    #[test]
+    fn test_relation_tuple() {
+        let relationship = Relationship {
+            resource: Some(ObjectReference {
+                object_type: "document".to_string(),
+                object_id: "doc1".to_string(),
+            }),
+            relation: "viewer".to_string(),
+            subject: Some(SubjectReference {
+                object: Some(ObjectReference {
+                    object_type: "user".to_string(),
+                    object_id: "user1".to_string(),
+                }),
+                optional_relation: String::new(),
+            }),
+            optional_caveat: None,
+        };
+
+        let tuple = relation_tuple(&relationship);
+        let object = tuple.object_and_relation.unwrap();
+        assert_eq!(
+            (object.namespace.as_str(), object.object_id.as_str()),
+            ("document", "doc1")
+        );
+        let Some(UserOneof::Userset(subject)) = tuple.user.unwrap().user_oneof else {
+            panic!("subject is not a userset");
+        };
+        assert_eq!(subject.relation, ELLIPSIS);
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let error = |line, message: &str| DeveloperError {
+            message: message.to_string(),
+            line,
+            column: 5,
+            ..Default::default()
+        };
+        let validation = Validation {
+            request_errors: vec![error(2, "unknown relation")],
+            validation_errors: vec![error(7, "assertion failed")],
+            ..Default::default()
+        };
+        assert_eq!(validation.errors().count(), 2);
+
+        let err = validation.into_result().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "2:5: unknown relation\n7:5: assertion failed"
+        );
+        assert!(Validation::default().into_result().is_ok());
+    }
+}
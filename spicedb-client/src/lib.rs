@@ -1,11 +1,30 @@
 #![doc = include_str!("../README.md")]
+// `tonic::Status` is large, and most errors returned by this crate carry one.
+#![allow(clippy::result_large_err)]
 
+#[cfg(feature = "alpha")]
+pub mod alpha;
+mod balance;
 pub mod builder;
+pub mod bulk;
 mod client;
+pub mod credentials;
+#[cfg(feature = "developer")]
+pub mod developer;
+#[cfg(feature = "materialize")]
+pub mod materialize;
 pub mod reader;
 pub mod result;
+pub mod retry;
+pub mod schema;
 #[cfg(feature = "futures")]
 pub mod stream;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod types;
+#[cfg(unix)]
+mod unix;
 
 pub use crate::client::*;
+#[cfg(feature = "tls")]
+pub use tonic::transport::{Certificate, ClientTlsConfig, Identity};
@@ -0,0 +1,341 @@
+//! Typed wrappers for the Materialize API, which streams changes to computed
+//! permissions to build denormalized permission indexes.
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use spicedb_grpc::authzed::api::materialize::v0::{
+    watch_permission_sets_response, watch_permissions_response, Cursor,
+    LookupPermissionSetsResponse, PermissionChange, PermissionSetChange,
+    WatchPermissionSetsResponse, WatchPermissionsResponse,
+};
+use spicedb_grpc::authzed::api::v1::ZedToken;
+use tokio::time::{sleep, Sleep};
+use tokio_stream::Stream;
+use tonic::{Status, Streaming};
+
+use crate::result::{Error, Result};
+use crate::retry::RetryPolicy;
+
+/// Event streamed by
+/// [`SpicedbClient::watch_permissions`](crate::SpicedbClient::watch_permissions).
+#[derive(Clone, Debug, PartialEq)]
+pub enum PermissionEvent {
+    /// A subject gained or lost a permission on a resource.
+    Change(PermissionChange),
+    /// All the changes up to this revision have been streamed.
+    Completed(ZedToken),
+}
+
+impl TryFrom<WatchPermissionsResponse> for PermissionEvent {
+    type Error = WatchPermissionsResponse;
+
+    fn try_from(response: WatchPermissionsResponse) -> Result<Self, Self::Error> {
+        use watch_permissions_response::Response;
+
+        match response.response {
+            Some(Response::Change(change)) => Ok(PermissionEvent::Change(change)),
+            Some(Response::CompletedRevision(revision)) => Ok(PermissionEvent::Completed(revision)),
+            None => Err(response),
+        }
+    }
+}
+
+/// Event streamed by
+/// [`SpicedbClient::watch_permission_sets`](crate::SpicedbClient::watch_permission_sets).
+#[derive(Clone, Debug, PartialEq)]
+pub enum PermissionSetEvent {
+    /// A member or a set was added to or removed from a set.
+    Change(PermissionSetChange),
+    /// All the changes up to this revision have been streamed.
+    Completed(ZedToken),
+    /// The changes cannot be streamed incrementally, and the permission sets
+    /// must be looked up again at this revision with
+    /// [`SpicedbClient::lookup_permission_sets`](crate::SpicedbClient::lookup_permission_sets).
+    LookupRequired(ZedToken),
+}
+
+impl TryFrom<WatchPermissionSetsResponse> for PermissionSetEvent {
+    type Error = WatchPermissionSetsResponse;
+
+    fn try_from(response: WatchPermissionSetsResponse) -> Result<Self, Self::Error> {
+        use watch_permission_sets_response::Response;
+
+        match response.response {
+            Some(Response::Change(change)) => Ok(PermissionSetEvent::Change(change)),
+            Some(Response::CompletedRevision(revision)) => {
+                Ok(PermissionSetEvent::Completed(revision))
+            }
+            Some(Response::LookupPermissionSetsRequired(required)) => Ok(
+                PermissionSetEvent::LookupRequired(required.required_lookup_at.unwrap_or_default()),
+            ),
+            None => Err(response),
+        }
+    }
+}
+
+/// Stream of typed events, skipping responses without an event, which may be
+/// sent by a newer server.
+pub struct Events<R, E> {
+    responses: Streaming<R>,
+    event: PhantomData<fn() -> E>,
+}
+
+/// Stream returned by
+/// [`SpicedbClient::watch_permissions`](crate::SpicedbClient::watch_permissions).
+pub type PermissionEvents = Events<WatchPermissionsResponse, PermissionEvent>;
+
+/// Stream returned by
+/// [`SpicedbClient::watch_permission_sets`](crate::SpicedbClient::watch_permission_sets).
+pub type PermissionSetEvents = Events<WatchPermissionSetsResponse, PermissionSetEvent>;
+
+impl<R, E> Events<R, E> {
+    pub(crate) fn new(responses: Streaming<R>) -> Self {
+        Self {
+            responses,
+            event: PhantomData,
+        }
+    }
+}
+
+impl<R, E> Stream for Events<R, E>
+where
+    E: TryFrom<R>,
+{
+    type Item = Result<E>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(Pin::new(&mut self.responses).poll_next(cx)) {
+                Some(Ok(response)) => {
+                    if let Ok(event) = E::try_from(response) {
+                        return Poll::Ready(Some(Ok(event)));
+                    }
+                }
+                Some(Err(status)) => return Poll::Ready(Some(Err(status.into()))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl<R, E> fmt::Debug for Events<R, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Events").finish_non_exhaustive()
+    }
+}
+
+pub(crate) type LookupPage =
+    Pin<Box<dyn Stream<Item = Result<LookupPermissionSetsResponse, Status>> + Send>>;
+
+pub(crate) type LookupConnect = Box<
+    dyn FnMut(Option<Cursor>) -> Pin<Box<dyn Future<Output = Result<LookupPage>> + Send>> + Send,
+>;
+
+enum LookupState {
+    Connect,
+    Connecting(Pin<Box<dyn Future<Output = Result<LookupPage>> + Send>>),
+    Receiving(LookupPage),
+    Backoff(Pin<Box<Sleep>>),
+    Done,
+}
+
+/// Stream of the permission set changes returned by
+/// [`SpicedbClient::lookup_permission_sets`](crate::SpicedbClient::lookup_permission_sets).
+///
+/// The permission sets are requested in pages of `limit` changes, each
+/// starting after the cursor of the last change received. When a page fails
+/// with a transient error, it is requested again according to the client's
+/// [`RetryPolicy`].
+///
+/// The [`cursor`](Self::cursor) can be saved to resume an interrupted lookup
+/// later.
+pub struct LookupPermissionSets {
+    connect: LookupConnect,
+    retry_policy: RetryPolicy,
+    limit: u32,
+    state: LookupState,
+    attempt: u32,
+    /// Number of changes received in the current page.
+    received: u32,
+    changes: VecDeque<PermissionSetChange>,
+    cursor: Option<Cursor>,
+}
+
+impl LookupPermissionSets {
+    pub(crate) fn new(
+        limit: u32,
+        cursor: Option<Cursor>,
+        retry_policy: RetryPolicy,
+        connect: LookupConnect,
+    ) -> Self {
+        Self {
+            connect,
+            retry_policy,
+            limit: limit.max(1),
+            state: LookupState::Connect,
+            attempt: 0,
+            received: 0,
+            changes: VecDeque::new(),
+            cursor,
+        }
+    }
+
+    /// Cursor after the last change yielded.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    /// Number of changes requested in each page.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+}
+
+impl Stream for LookupPermissionSets {
+    type Item = Result<PermissionSetChange>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(change) = this.changes.pop_front() {
+                return Poll::Ready(Some(Ok(change)));
+            }
+
+            match &mut this.state {
+                LookupState::Connect => {
+                    this.received = 0;
+                    this.state = LookupState::Connecting((this.connect)(this.cursor.clone()));
+                }
+                LookupState::Connecting(connecting) => match ready!(connecting.as_mut().poll(cx)) {
+                    Ok(page) => this.state = LookupState::Receiving(page),
+                    Err(err) => {
+                        this.state = LookupState::Done;
+                        return Poll::Ready(Some(Err(err)));
+                    }
+                },
+                LookupState::Receiving(page) => match ready!(page.as_mut().poll_next(cx)) {
+                    Some(Ok(response)) => {
+                        this.attempt = 0;
+                        this.received += 1;
+                        if response.cursor.is_some() {
+                            this.cursor = response.cursor;
+                        }
+                        this.changes.extend(response.change);
+                    }
+                    Some(Err(status)) => {
+                        let err = Error::from(status);
+                        this.attempt += 1;
+                        if !this.retry_policy.should_retry(this.attempt, &err) {
+                            this.state = LookupState::Done;
+                            return Poll::Ready(Some(Err(err)));
+                        }
+                        let backoff = this.retry_policy.backoff(this.attempt);
+                        this.state = LookupState::Backoff(Box::pin(sleep(backoff)));
+                    }
+                    None if this.received >= this.limit => this.state = LookupState::Connect,
+                    None => this.state = LookupState::Done,
+                },
+                LookupState::Backoff(backoff) => {
+                    ready!(backoff.as_mut().poll(cx));
+                    this.state = LookupState::Connect;
+                }
+                LookupState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl fmt::Debug for LookupPermissionSets {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LookupPermissionSets")
+            .field("limit", &self.limit)
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use spicedb_grpc::authzed::api::materialize::v0::SetReference;
+    use tokio::test;
+    use tokio_stream::StreamExt;
+
+    use super::*;
+
+    fn response(n: u32) -> Result<LookupPermissionSetsResponse, Status> {
+        Ok(LookupPermissionSetsResponse {
+            change: Some(PermissionSetChange {
+                parent_set: Some(SetReference {
+                    object_id: n.to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            cursor: Some(Cursor {
+                starting_index: n + 1,
+                ..Default::default()
+            }),
+        })
+    }
+
+    #[test]
+    pub async fn test_lookup_permission_sets() {
+        let requested = Arc::new(Mutex::new(Vec::new()));
+        let connect: LookupConnect = Box::new({
+            let requested = requested.clone();
+            move |cursor| {
+                let start = cursor.map_or(0, |cursor| cursor.starting_index);
+                let mut requested = requested.lock().unwrap();
+                requested.push(start);
+                let page: Vec<_> = match (start, requested.len()) {
+                    // The first attempt at the second page fails.
+                    (2, 2) => vec![response(2), Err(Status::unavailable("restarting"))],
+                    _ => (start..5.min(start + 2)).map(response).collect(),
+                };
+                Box::pin(async move { Ok(Box::pin(tokio_stream::iter(page)) as LookupPage) })
+            }
+        });
+
+        let mut policy = RetryPolicy::new();
+        policy.initial_backoff(Duration::from_millis(1));
+        let mut lookup = LookupPermissionSets::new(2, None, policy, connect);
+
+        let mut ids = Vec::new();
+        while let Some(change) = lookup.next().await {
+            ids.push(change.unwrap().parent_set.unwrap().object_id);
+        }
+        assert_eq!(ids, ["0", "1", "2", "3", "4"]);
+        assert_eq!(*requested.lock().unwrap(), [0, 2, 3, 5]);
+        assert_eq!(lookup.cursor().unwrap().starting_index, 5);
+    }
+
+    #[test]
+    pub async fn test_permission_set_event() {
+        use watch_permission_sets_response::Response;
+
+        let response = WatchPermissionSetsResponse {
+            response: Some(Response::CompletedRevision(ZedToken {
+                token: "token".to_string(),
+            })),
+        };
+        assert_eq!(
+            PermissionSetEvent::try_from(response),
+            Ok(PermissionSetEvent::Completed(ZedToken {
+                token: "token".to_string()
+            }))
+        );
+
+        let response = WatchPermissionSetsResponse { response: None };
+        assert!(PermissionSetEvent::try_from(response).is_err());
+    }
+}
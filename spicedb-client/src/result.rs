@@ -1,11 +1,25 @@
+use std::collections::HashMap;
+
 use http::uri::InvalidUri;
+use prost::Message;
+use spicedb_grpc::authzed::api::v1::ErrorReason;
+use spicedb_grpc::google::rpc::{ErrorInfo, Status as RpcStatus};
 use thiserror::Error;
 use tonic::metadata::errors::InvalidMetadataValue;
+use tonic::{Code, Status};
 
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
 
+/// Error domain used by SpiceDB in `google.rpc.ErrorInfo` details.
+const AUTHZED_DOMAIN: &str = "authzed.com";
+
+const ERROR_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("failed to load credentials: {0}")]
+    Credentials(#[source] Box<dyn std::error::Error + Send + Sync>),
+
     #[error(transparent)]
     InvalidMetadataValue(#[from] InvalidMetadataValue),
 
@@ -15,6 +29,243 @@ pub enum Error {
     #[error(transparent)]
     TonicTransport(#[from] tonic::transport::Error),
 
+    /// A write or delete precondition was not satisfied.
+    ///
+    /// The metadata describes the failed precondition filter.
+    #[error("precondition failed: {}", .status.message())]
+    PreconditionFailed {
+        metadata: HashMap<String, String>,
+        status: Status,
+    },
+
+    /// The schema could not be parsed. Line and column are 0-indexed and may
+    /// not be reported by the server.
+    #[error("schema parse error: {}", .status.message())]
+    SchemaParseError {
+        line: Option<u32>,
+        column: Option<u32>,
+        source_code: Option<String>,
+        status: Status,
+    },
+
+    /// The request referenced a relation or permission that does not exist on
+    /// the definition.
+    #[error("unknown relation or permission `{relation}` on `{definition}`")]
+    UnknownRelationOrPermission {
+        definition: String,
+        relation: String,
+        status: Status,
+    },
+
+    /// The cursor provided with the request was invalid.
+    #[error("invalid cursor: {}", .status.message())]
+    InvalidCursor { status: Status },
+
+    /// The datastore aborted the transaction because it conflicted with
+    /// another. The request can be retried.
+    #[error("serialization failure: {}", .status.message())]
+    SerializationFailure { status: Status },
+
+    /// A write was sent to a SpiceDB instance running in read-only mode.
+    #[error("service is read-only: {}", .status.message())]
+    ServiceReadOnly { status: Status },
+
+    /// The request exceeded the maximum dispatch depth, usually because of a
+    /// cycle in the data.
+    #[error("maximum depth exceeded: {}", .status.message())]
+    MaximumDepthExceeded {
+        maximum_depth: Option<u32>,
+        status: Status,
+    },
+
+    /// The developer service reported errors in a schema, relationships or
+    /// assertions.
+    #[cfg(feature = "developer")]
+    #[error("{}", crate::developer::format_errors(.0))]
+    Developer(Vec<spicedb_grpc::authzed::api::v0::DeveloperError>),
+
+    /// Any other error returned by the server.
     #[error(transparent)]
-    TonicStatus(#[from] tonic::Status),
+    TonicStatus(Status),
+}
+
+impl Error {
+    /// The gRPC status returned by the server, if any.
+    pub fn status(&self) -> Option<&Status> {
+        match self {
+            Error::PreconditionFailed { status, .. }
+            | Error::SchemaParseError { status, .. }
+            | Error::UnknownRelationOrPermission { status, .. }
+            | Error::InvalidCursor { status }
+            | Error::SerializationFailure { status }
+            | Error::ServiceReadOnly { status }
+            | Error::MaximumDepthExceeded { status, .. }
+            | Error::TonicStatus(status) => Some(status),
+            _ => None,
+        }
+    }
+
+    /// The gRPC status code returned by the server, if any.
+    pub fn code(&self) -> Option<Code> {
+        self.status().map(Status::code)
+    }
+
+    /// The SpiceDB `ErrorInfo` attached to the status, if any.
+    pub fn error_info(&self) -> Option<ErrorInfo> {
+        self.status().and_then(error_info)
+    }
+
+    /// The SpiceDB error reason attached to the status, if any.
+    pub fn reason(&self) -> Option<ErrorReason> {
+        self.error_info()
+            .and_then(|info| ErrorReason::from_str_name(&info.reason))
+    }
+}
+
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        let Some(info) = error_info(&status) else {
+            return Error::TonicStatus(status);
+        };
+        let Some(reason) = ErrorReason::from_str_name(&info.reason) else {
+            return Error::TonicStatus(status);
+        };
+
+        let mut metadata = info.metadata;
+        let number = |metadata: &HashMap<String, String>, key: &str| {
+            metadata.get(key).and_then(|value| value.parse().ok())
+        };
+
+        match reason {
+            ErrorReason::WriteOrDeletePreconditionFailure => {
+                Error::PreconditionFailed { metadata, status }
+            }
+            ErrorReason::SchemaParseError => Error::SchemaParseError {
+                line: number(&metadata, "start_line_number"),
+                column: number(&metadata, "start_column_position"),
+                source_code: metadata.remove("source_code"),
+                status,
+            },
+            ErrorReason::UnknownRelationOrPermission => Error::UnknownRelationOrPermission {
+                definition: metadata.remove("definition_name").unwrap_or_default(),
+                relation: metadata
+                    .remove("relation_or_permission_name")
+                    .unwrap_or_default(),
+                status,
+            },
+            ErrorReason::InvalidCursor => Error::InvalidCursor { status },
+            ErrorReason::SerializationFailure => Error::SerializationFailure { status },
+            ErrorReason::ServiceReadOnly => Error::ServiceReadOnly { status },
+            ErrorReason::MaximumDepthExceeded => Error::MaximumDepthExceeded {
+                maximum_depth: number(&metadata, "maximum_depth_allowed"),
+                status,
+            },
+            _ => Error::TonicStatus(status),
+        }
+    }
+}
+
+/// Decode the SpiceDB `ErrorInfo` from the `google.rpc.Status` details.
+fn error_info(status: &Status) -> Option<ErrorInfo> {
+    if status.details().is_empty() {
+        return None;
+    }
+
+    RpcStatus::decode(status.details())
+        .ok()?
+        .details
+        .into_iter()
+        .filter(|any| any.type_url == ERROR_INFO_TYPE_URL)
+        .filter_map(|any| ErrorInfo::decode(any.value.as_slice()).ok())
+        .find(|info| info.domain == AUTHZED_DOMAIN)
+}
+
+#[cfg(test)]
+mod test {
+    use prost_types::Any;
+
+    use super::*;
+
+    fn status(code: Code, reason: ErrorReason, metadata: &[(&str, &str)]) -> Status {
+        let info = ErrorInfo {
+            reason: reason.as_str_name().to_string(),
+            domain: AUTHZED_DOMAIN.to_string(),
+            metadata: metadata
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        };
+        let details = RpcStatus {
+            code: code as i32,
+            message: "message".to_string(),
+            details: vec![Any {
+                type_url: ERROR_INFO_TYPE_URL.to_string(),
+                value: info.encode_to_vec(),
+            }],
+        };
+        Status::with_details(code, "message", details.encode_to_vec().into())
+    }
+
+    #[test]
+    fn test_schema_parse_error() {
+        let error = Error::from(status(
+            Code::InvalidArgument,
+            ErrorReason::SchemaParseError,
+            &[
+                ("start_line_number", "1"),
+                ("start_column_position", "19"),
+                ("source_code", "somedefinition"),
+            ],
+        ));
+        assert!(matches!(
+            &error,
+            Error::SchemaParseError {
+                line: Some(1),
+                column: Some(19),
+                source_code: Some(source_code),
+                ..
+            } if source_code == "somedefinition"
+        ));
+        assert_eq!(error.code(), Some(Code::InvalidArgument));
+        assert_eq!(error.reason(), Some(ErrorReason::SchemaParseError));
+    }
+
+    #[test]
+    fn test_unknown_relation_or_permission() {
+        let error = Error::from(status(
+            Code::FailedPrecondition,
+            ErrorReason::UnknownRelationOrPermission,
+            &[
+                ("definition_name", "document"),
+                ("relation_or_permission_name", "viewer"),
+            ],
+        ));
+        assert!(matches!(
+            error,
+            Error::UnknownRelationOrPermission { definition, relation, .. }
+                if definition == "document" && relation == "viewer"
+        ));
+    }
+
+    #[test]
+    fn test_precondition_failed() {
+        let error = Error::from(status(
+            Code::FailedPrecondition,
+            ErrorReason::WriteOrDeletePreconditionFailure,
+            &[("precondition_resource_type", "document")],
+        ));
+        assert!(matches!(
+            error,
+            Error::PreconditionFailed { metadata, .. }
+                if metadata["precondition_resource_type"] == "document"
+        ));
+    }
+
+    #[test]
+    fn test_without_details() {
+        let error = Error::from(Status::unavailable("unavailable"));
+        assert!(matches!(error, Error::TonicStatus(_)));
+        assert_eq!(error.code(), Some(Code::Unavailable));
+        assert_eq!(error.reason(), None);
+    }
 }
@@ -0,0 +1,369 @@
+//! Automatic retries of transient failures.
+//!
+//! The [`SpicedbClient`](crate::SpicedbClient) retries requests that failed
+//! with `UNAVAILABLE`, `DEADLINE_EXCEEDED` or a SpiceDB serialization failure,
+//! but only when the request is safe to send again, see [`IdempotentRequest`]
+//! and [`RetryPolicy::idempotency`].
+//! Streaming requests are only retried until the response stream is opened.
+
+use std::any::Any;
+use std::future::Future;
+use std::time::Duration;
+
+use spicedb_grpc::authzed::api::v1::*;
+use tokio::time::sleep;
+use tonic::{Code, Request, Response, Status};
+
+use crate::credentials::{authorized_request, Credentials};
+use crate::result::{Error, Result};
+use crate::types::RelationshipUpdateOperation;
+
+/// Retry policy with exponential backoff and jitter.
+///
+/// The delay before retry `n` (starting at 1) is
+/// `initial_backoff * multiplier^(n - 1)`, capped at `max_backoff`, and then
+/// randomized by up to `jitter` in either direction.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: f64,
+    idempotency: Option<fn(&dyn Any) -> Option<bool>>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(2),
+            multiplier: 2.0,
+            jitter: 0.2,
+            idempotency: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// A policy that never retries.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Maximum number of attempts, including the first one.
+    pub fn max_attempts(&mut self, max_attempts: u32) -> &mut Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry.
+    pub fn initial_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Upper bound on the delay between retries, before jitter.
+    pub fn max_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Factor by which the delay grows after each retry.
+    pub fn multiplier(&mut self, multiplier: f64) -> &mut Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Fraction of the delay, between 0 and 1, to randomly add or subtract.
+    pub fn jitter(&mut self, jitter: f64) -> &mut Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Override whether requests are idempotent, and so retried.
+    ///
+    /// The function receives the request message and returns whether it is
+    /// idempotent, or `None` to fall back to [`IdempotentRequest`]. This lets
+    /// callers opt in writes that are safe to repeat in their application, or
+    /// opt out requests that are not.
+    ///
+    /// ```rust
+    /// # use spicedb_client::retry::RetryPolicy;
+    /// use spicedb_grpc::authzed::api::v1::DeleteRelationshipsRequest;
+    ///
+    /// let mut policy = RetryPolicy::new();
+    /// policy.idempotency(|request| {
+    ///     // Limited deletes are safe to repeat in this application.
+    ///     request.downcast_ref::<DeleteRelationshipsRequest>().map(|_| true)
+    /// });
+    /// ```
+    pub fn idempotency(&mut self, idempotency: fn(&dyn Any) -> Option<bool>) -> &mut Self {
+        self.idempotency = Some(idempotency);
+        self
+    }
+
+    pub fn clear_idempotency(&mut self) -> &mut Self {
+        self.idempotency = None;
+        self
+    }
+
+    /// Whether `request` is idempotent, according to the
+    /// [`idempotency`](Self::idempotency) override or else to
+    /// [`IdempotentRequest`].
+    pub fn is_idempotent<M: IdempotentRequest + Any>(&self, request: &M) -> bool {
+        self.idempotency
+            .and_then(|idempotency| idempotency(request))
+            .unwrap_or_else(|| request.is_idempotent())
+    }
+
+    /// Whether the error is transient and the request may succeed if retried.
+    pub fn is_retryable(&self, err: &Error) -> bool {
+        match err {
+            Error::SerializationFailure { .. } => true,
+            _ => matches!(err.code(), Some(Code::Unavailable | Code::DeadlineExceeded)),
+        }
+    }
+
+    /// Whether a request that failed on attempt `attempt` (starting at 1)
+    /// should be retried.
+    pub fn should_retry(&self, attempt: u32, err: &Error) -> bool {
+        attempt < self.max_attempts && self.is_retryable(err)
+    }
+
+    /// Delay before retry `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = (self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent))
+            .min(self.max_backoff.as_secs_f64());
+        let jitter = 1.0 + self.jitter * (rand::random::<f64>() * 2.0 - 1.0);
+        Duration::from_secs_f64(backoff * jitter)
+    }
+}
+
+/// Send a request authorized with `credentials`, retrying transient failures
+/// according to `retry_policy` if the request is idempotent, see
+/// [`RetryPolicy::is_idempotent`].
+pub(crate) async fn call_with_retry<M, R, F, Fut>(
+    credentials: Option<&Credentials>,
+    retry_policy: &RetryPolicy,
+    message: M,
+    mut send: F,
+) -> Result<R>
+where
+    M: Clone + IdempotentRequest + Any,
+    F: FnMut(Request<M>) -> Fut,
+    Fut: Future<Output = Result<Response<R>, Status>>,
+{
+    let idempotent = retry_policy.is_idempotent(&message);
+    let mut attempt = 1;
+    loop {
+        let request = authorized_request(credentials, message.clone()).await?;
+        let err = match send(request).await {
+            Ok(response) => return Ok(response.into_inner()),
+            Err(status) => Error::from(status),
+        };
+        if !idempotent || !retry_policy.should_retry(attempt, &err) {
+            return Err(err);
+        }
+        sleep(retry_policy.backoff(attempt)).await;
+        attempt += 1;
+    }
+}
+
+/// Whether a request can safely be sent more than once.
+pub trait IdempotentRequest {
+    fn is_idempotent(&self) -> bool;
+}
+
+macro_rules! idempotent {
+    ($($request:ty),* $(,)?) => {
+        $(
+            impl IdempotentRequest for $request {
+                fn is_idempotent(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+idempotent!(
+    ReadSchemaRequest,
+    WriteSchemaRequest,
+    ReadRelationshipsRequest,
+    CheckPermissionRequest,
+    CheckBulkPermissionsRequest,
+    ExpandPermissionTreeRequest,
+    LookupResourcesRequest,
+    LookupSubjectsRequest,
+    WatchRequest,
+    BulkExportRelationshipsRequest,
+    ExperimentalReflectSchemaRequest,
+    ExperimentalComputablePermissionsRequest,
+    ExperimentalDependentRelationsRequest,
+    ExperimentalDiffSchemaRequest,
+    ExperimentalCountRelationshipsRequest,
+);
+
+#[cfg(feature = "alpha")]
+idempotent!(spicedb_grpc::authzed::api::v1alpha1::WatchResourcesRequest);
+
+#[cfg(feature = "developer")]
+idempotent!(
+    spicedb_grpc::authzed::api::v0::EditCheckRequest,
+    spicedb_grpc::authzed::api::v0::ValidateRequest,
+    spicedb_grpc::authzed::api::v0::FormatSchemaRequest,
+    spicedb_grpc::authzed::api::v0::UpgradeSchemaRequest,
+);
+
+#[cfg(feature = "materialize")]
+idempotent!(
+    spicedb_grpc::authzed::api::materialize::v0::WatchPermissionsRequest,
+    spicedb_grpc::authzed::api::materialize::v0::WatchPermissionSetsRequest,
+    spicedb_grpc::authzed::api::materialize::v0::LookupPermissionSetsRequest,
+);
+
+/// Registering a counter fails with `ALREADY_EXISTS` if the first attempt was
+/// committed, and unregistering fails with `NOT_FOUND`.
+impl IdempotentRequest for ExperimentalRegisterRelationshipCounterRequest {
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+}
+
+impl IdempotentRequest for ExperimentalUnregisterRelationshipCounterRequest {
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+}
+
+/// Writes are idempotent if they only touch or delete relationships, or are
+/// guarded by preconditions. Re-sending a create could fail with
+/// `ALREADY_EXISTS` after the first attempt was committed.
+impl IdempotentRequest for WriteRelationshipsRequest {
+    fn is_idempotent(&self) -> bool {
+        !self.optional_preconditions.is_empty()
+            || self.updates.iter().all(|update| {
+                matches!(
+                    update.operation(),
+                    RelationshipUpdateOperation::Touch | RelationshipUpdateOperation::Delete
+                )
+            })
+    }
+}
+
+/// Deletes are idempotent unless they are limited, in which case a retry could
+/// delete a second batch of relationships. Preconditions do not help, since
+/// they usually still hold after the first batch was deleted.
+impl IdempotentRequest for DeleteRelationshipsRequest {
+    fn is_idempotent(&self) -> bool {
+        self.optional_limit == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tonic::Status;
+
+    use crate::builder::{RelationshipFilterBuilder, WriteRelationshipsRequestBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let mut policy = RetryPolicy::new();
+        policy
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .multiplier(2.0)
+            .jitter(0.0);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(100), Duration::from_millis(300));
+
+        policy.jitter(0.5);
+        for _ in 0..100 {
+            let backoff = policy.backoff(1);
+            assert!(backoff >= Duration::from_millis(50) && backoff <= Duration::from_millis(150));
+        }
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::new();
+        let unavailable = Error::from(Status::unavailable("unavailable"));
+        let invalid = Error::from(Status::invalid_argument("invalid"));
+
+        assert!(policy.should_retry(1, &unavailable));
+        assert!(policy.should_retry(2, &unavailable));
+        assert!(!policy.should_retry(3, &unavailable));
+        assert!(!policy.should_retry(1, &invalid));
+        assert!(!RetryPolicy::disabled().should_retry(1, &unavailable));
+    }
+
+    #[test]
+    fn test_write_relationships_idempotency() {
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.update_relationship("document", "doc1", "viewer", "user", "user1");
+        request.delete_relationship("document", "doc1", "editor", "user", "user1");
+        assert!(request.is_idempotent());
+
+        request.create_relationship("document", "doc2", "viewer", "user", "user1");
+        assert!(!request.is_idempotent());
+
+        request.not_match_precondition().resource_type("document");
+        assert!(request.is_idempotent());
+    }
+
+    #[test]
+    fn test_delete_relationships_idempotency() {
+        let mut request = DeleteRelationshipsRequest::default();
+        assert!(request.is_idempotent());
+
+        request.optional_limit = 100;
+        assert!(!request.is_idempotent());
+
+        request.optional_preconditions.push(Precondition::default());
+        assert!(!request.is_idempotent());
+    }
+
+    #[test]
+    fn test_idempotency_override() {
+        let delete = DeleteRelationshipsRequest {
+            optional_limit: 100,
+            ..Default::default()
+        };
+        let read = ReadSchemaRequest {};
+
+        let mut policy = RetryPolicy::new();
+        assert!(!policy.is_idempotent(&delete));
+        assert!(policy.is_idempotent(&read));
+
+        policy.idempotency(|request| {
+            if request.is::<DeleteRelationshipsRequest>() {
+                Some(true)
+            } else if request.is::<ReadSchemaRequest>() {
+                Some(false)
+            } else {
+                None
+            }
+        });
+        assert!(policy.is_idempotent(&delete));
+        assert!(!policy.is_idempotent(&read));
+        assert!(policy.is_idempotent(&CheckPermissionRequest::default()));
+
+        policy.clear_idempotency();
+        assert!(!policy.is_idempotent(&delete));
+    }
+}
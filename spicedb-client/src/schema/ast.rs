@@ -0,0 +1,341 @@
+//! Syntax tree of the schema language, produced by [`parse`](super::parse).
+//!
+//! Every node keeps the [`Span`] of the text it was parsed from, so tools can
+//! report errors at their location in the schema.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::{parse, CaveatParameterType, ParseError, SubjectTypeKind};
+
+/// Byte range of a node in the schema text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// The span from the start of `self` to the end of `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+
+    /// The 1-based line and column of the start of the span in `text`.
+    pub fn line_column(&self, text: &str) -> (usize, usize) {
+        let before = &text[..self.start.min(text.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
+}
+
+/// A name, such as `document`, `tenant/document` or `viewer`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ident {
+    pub name: String,
+    pub span: Span,
+}
+
+impl Ident {
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for Ident {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// A parsed schema, with its items in source order.
+///
+/// ```rust
+/// # use spicedb_client::schema::ast::Schema;
+/// #
+/// let schema: Schema = "definition user {} definition document { relation viewer: user }"
+///     .parse()
+///     .unwrap();
+/// let viewer = schema.definition("document").unwrap().relation("viewer").unwrap();
+/// assert_eq!(viewer.subject_types[0].definition.as_str(), "user");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Schema {
+    /// Comments before the leading `use` flags, including the comment
+    /// delimiters. Comments before a definition or caveat are kept on it.
+    pub comment: String,
+    pub items: Vec<Item>,
+    /// Comments after the last item, including the comment delimiters.
+    pub end_comment: String,
+}
+
+impl Schema {
+    pub fn definitions(&self) -> impl Iterator<Item = &Definition> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Definition(definition) => Some(definition),
+            _ => None,
+        })
+    }
+
+    pub fn caveats(&self) -> impl Iterator<Item = &Caveat> {
+        self.items.iter().filter_map(|item| match item {
+            Item::Caveat(caveat) => Some(caveat),
+            _ => None,
+        })
+    }
+
+    pub fn definition(&self, name: &str) -> Option<&Definition> {
+        self.definitions()
+            .find(|definition| definition.name.as_str() == name)
+    }
+
+    pub fn caveat(&self, name: &str) -> Option<&Caveat> {
+        self.caveats().find(|caveat| caveat.name.as_str() == name)
+    }
+}
+
+impl FromStr for Schema {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse(text)
+    }
+}
+
+/// A top-level item of a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Item {
+    /// A language feature flag, such as `use expiration`.
+    Use(Ident),
+    Caveat(Caveat),
+    Definition(Definition),
+}
+
+impl Item {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Use(flag) => flag.span,
+            Self::Caveat(caveat) => caveat.span,
+            Self::Definition(definition) => definition.span,
+        }
+    }
+}
+
+/// An object definition, such as `definition document { ... }`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Definition {
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub name: Ident,
+    /// Relations and permissions in source order.
+    pub members: Vec<Member>,
+    /// Comments after the last member, before the closing brace.
+    pub end_comment: String,
+    /// Comment after the closing brace, on the same line.
+    pub trailing_comment: String,
+    pub span: Span,
+}
+
+impl Definition {
+    pub fn relations(&self) -> impl Iterator<Item = &Relation> {
+        self.members.iter().filter_map(|member| match member {
+            Member::Relation(relation) => Some(relation),
+            Member::Permission(_) => None,
+        })
+    }
+
+    pub fn permissions(&self) -> impl Iterator<Item = &Permission> {
+        self.members.iter().filter_map(|member| match member {
+            Member::Relation(_) => None,
+            Member::Permission(permission) => Some(permission),
+        })
+    }
+
+    /// The relation or permission named `name`.
+    pub fn member(&self, name: &str) -> Option<&Member> {
+        self.members
+            .iter()
+            .find(|member| member.name().as_str() == name)
+    }
+
+    pub fn relation(&self, name: &str) -> Option<&Relation> {
+        self.relations()
+            .find(|relation| relation.name.as_str() == name)
+    }
+
+    pub fn permission(&self, name: &str) -> Option<&Permission> {
+        self.permissions()
+            .find(|permission| permission.name.as_str() == name)
+    }
+}
+
+/// A relation or permission of a definition.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Member {
+    Relation(Relation),
+    Permission(Permission),
+}
+
+impl Member {
+    pub fn name(&self) -> &Ident {
+        match self {
+            Self::Relation(relation) => &relation.name,
+            Self::Permission(permission) => &permission.name,
+        }
+    }
+
+    pub fn comment(&self) -> &str {
+        match self {
+            Self::Relation(relation) => &relation.comment,
+            Self::Permission(permission) => &permission.comment,
+        }
+    }
+
+    /// Comment after the member, on the same line.
+    pub fn trailing_comment(&self) -> &str {
+        match self {
+            Self::Relation(relation) => &relation.trailing_comment,
+            Self::Permission(permission) => &permission.trailing_comment,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Relation(relation) => relation.span,
+            Self::Permission(permission) => permission.span,
+        }
+    }
+}
+
+/// A relation, such as `relation viewer: user | group#member`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Relation {
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub name: Ident,
+    pub subject_types: Vec<SubjectType>,
+    /// Comment after the relation, on the same line.
+    pub trailing_comment: String,
+    pub span: Span,
+}
+
+/// An allowed subject type of a relation, such as `user`, `group#member`,
+/// `user:*` or `user with ip_allowlist and expiration`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubjectType {
+    /// Name of the subject's definition.
+    pub definition: Ident,
+    pub kind: SubjectTypeKind,
+    /// Caveat that must be satisfied for the relationship, if any.
+    pub caveat: Option<Ident>,
+    /// Whether relationships may expire.
+    pub expiration: bool,
+    pub span: Span,
+}
+
+/// A permission, such as `permission view = viewer + editor`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Permission {
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub name: Ident,
+    pub expression: Expr,
+    /// Comment after the permission, on the same line.
+    pub trailing_comment: String,
+    pub span: Span,
+}
+
+/// A permission expression.
+///
+/// Operands of consecutive unions and intersections are collected in a single
+/// node, while parenthesized operands are kept as nested nodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    /// `nil`, the empty set of subjects.
+    Nil(Span),
+    /// A relation or permission of the same object.
+    Ref(Ident),
+    /// A relation or permission of the objects of a relation, such as
+    /// `parent->view` or `parent.all(view)`.
+    Arrow {
+        tupleset: Ident,
+        function: ArrowFunction,
+        computed: Ident,
+        span: Span,
+    },
+    Union {
+        operands: Vec<Expr>,
+        span: Span,
+    },
+    Intersection {
+        operands: Vec<Expr>,
+        span: Span,
+    },
+    Exclusion {
+        base: Box<Expr>,
+        excluded: Box<Expr>,
+        span: Span,
+    },
+}
+
+impl Expr {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Nil(span) => *span,
+            Self::Ref(name) => name.span,
+            Self::Arrow { span, .. }
+            | Self::Union { span, .. }
+            | Self::Intersection { span, .. }
+            | Self::Exclusion { span, .. } => *span,
+        }
+    }
+}
+
+/// How an arrow combines the subjects of the objects of its relation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ArrowFunction {
+    /// `relation->permission`, the union over the objects.
+    #[default]
+    Arrow,
+    /// `relation.any(permission)`, the same as [`ArrowFunction::Arrow`].
+    Any,
+    /// `relation.all(permission)`, the intersection over the objects.
+    All,
+}
+
+/// A caveat, such as `caveat ip_allowlist(user_ip ipaddress, cidrs list<string>) { ... }`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Caveat {
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub name: Ident,
+    pub parameters: Vec<CaveatParameter>,
+    /// The CEL expression of the caveat, without the surrounding braces and
+    /// the indentation common to its lines.
+    pub expression: String,
+    pub expression_span: Span,
+    /// Comment after the closing brace, on the same line.
+    pub trailing_comment: String,
+    pub span: Span,
+}
+
+impl Caveat {
+    pub fn parameter(&self, name: &str) -> Option<&CaveatParameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name.as_str() == name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaveatParameter {
+    pub name: Ident,
+    pub parameter_type: CaveatParameterType,
+    pub type_span: Span,
+    pub span: Span,
+}
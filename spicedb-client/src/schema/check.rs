@@ -0,0 +1,712 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use super::ast::{Caveat, Definition, Expr, Item, Member, Permission, Relation, Schema, Span};
+use super::{CaveatParameterType, SubjectTypeKind};
+
+/// Pattern of definition names, from the validation rules of the API.
+const DEFINITION_NAME_PATTERN: &str =
+    "^([a-z][a-z0-9_]{1,61}[a-z0-9]/)*[a-z][a-z0-9_]{1,62}[a-z0-9]$";
+
+/// Pattern of relation and permission names.
+const RELATION_NAME_PATTERN: &str = "^[a-z][a-z0-9_]{1,62}[a-z0-9]$";
+
+/// Pattern of caveat names.
+const CAVEAT_NAME_PATTERN: &str = "^([a-zA-Z0-9_][a-zA-Z0-9/_|-]{0,127})$";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The schema would be rejected by SpiceDB.
+    Error,
+    /// The schema is valid but likely contains a mistake.
+    Warning,
+}
+
+/// An error or warning reported by [`check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+}
+
+impl Diagnostic {
+    fn error(message: String, span: Span) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+            span,
+        }
+    }
+
+    fn warning(message: String, span: Span) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message,
+            span,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.severity {
+            Severity::Error => write!(f, "error: {}", self.message),
+            Severity::Warning => write!(f, "warning: {}", self.message),
+        }
+    }
+}
+
+/// Check a parsed schema without a SpiceDB server, returning the diagnostics
+/// in source order.
+///
+/// Errors are reported for invalid names, duplicate names, references to
+/// undefined definitions, relations, permissions and caveats, invalid arrows
+/// and invalid caveat parameters. Warnings are reported for unused relations
+/// and caveats, and for permissions that are always empty.
+///
+/// ```rust
+/// # use spicedb_client::schema::{check, parse};
+/// #
+/// let text = "definition user {}\ndefinition document {\n\tpermission view = viewer\n}";
+/// let schema = parse(text).unwrap();
+/// for diagnostic in check(&schema) {
+///     let (line, column) = diagnostic.span.line_column(text);
+///     eprintln!("{line}:{column}: {diagnostic}");
+/// }
+/// assert!(check(&schema).iter().any(|diagnostic| diagnostic.is_error()));
+/// ```
+pub fn check(schema: &Schema) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        schema,
+        definitions: HashMap::new(),
+        caveats: HashMap::new(),
+        expiration: schema
+            .items
+            .iter()
+            .any(|item| matches!(item, Item::Use(flag) if flag.as_str() == "expiration")),
+        diagnostics: Vec::new(),
+    };
+    checker.collect_names();
+    for definition in schema.definitions() {
+        checker.check_definition(definition);
+    }
+    for caveat in schema.caveats() {
+        checker.check_caveat(caveat);
+    }
+    checker.lint_unused();
+    checker.lint_empty_permissions();
+
+    let mut diagnostics = checker.diagnostics;
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.span.start, diagnostic.severity));
+    diagnostics
+}
+
+struct Checker<'a> {
+    schema: &'a Schema,
+    /// The first definition with each name.
+    definitions: HashMap<&'a str, &'a Definition>,
+    /// The first caveat with each name.
+    caveats: HashMap<&'a str, &'a Caveat>,
+    /// Whether `use expiration` is set.
+    expiration: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Checker<'a> {
+    fn error(&mut self, message: String, span: Span) {
+        self.diagnostics.push(Diagnostic::error(message, span));
+    }
+
+    fn warning(&mut self, message: String, span: Span) {
+        self.diagnostics.push(Diagnostic::warning(message, span));
+    }
+
+    fn collect_names(&mut self) {
+        for definition in self.schema.definitions() {
+            let name = definition.name.as_str();
+            if self.definitions.contains_key(name) {
+                self.error(
+                    format!("duplicate definition `{name}`"),
+                    definition.name.span,
+                );
+                continue;
+            }
+            self.definitions.insert(name, definition);
+            if !is_definition_name(name) {
+                self.error(
+                    format!("definition name `{name}` must match `{DEFINITION_NAME_PATTERN}`"),
+                    definition.name.span,
+                );
+            }
+        }
+        for caveat in self.schema.caveats() {
+            let name = caveat.name.as_str();
+            if self.caveats.contains_key(name) {
+                self.error(format!("duplicate caveat `{name}`"), caveat.name.span);
+                continue;
+            }
+            self.caveats.insert(name, caveat);
+            if !is_caveat_name(name) {
+                self.error(
+                    format!("caveat name `{name}` must match `{CAVEAT_NAME_PATTERN}`"),
+                    caveat.name.span,
+                );
+            }
+        }
+    }
+
+    fn check_definition(&mut self, definition: &'a Definition) {
+        let mut names = HashSet::new();
+        for member in &definition.members {
+            let name = member.name();
+            if !names.insert(name.as_str()) {
+                self.error(
+                    format!(
+                        "duplicate relation or permission `{name}` under definition `{}`",
+                        definition.name
+                    ),
+                    name.span,
+                );
+            }
+            if !is_relation_name(name.as_str()) {
+                self.error(
+                    format!(
+                        "relation or permission name `{name}` must match `{RELATION_NAME_PATTERN}`"
+                    ),
+                    name.span,
+                );
+            }
+            match member {
+                Member::Relation(relation) => self.check_relation(relation),
+                Member::Permission(permission) => {
+                    self.check_expr(definition, permission, &permission.expression)
+                }
+            }
+        }
+    }
+
+    fn check_relation(&mut self, relation: &Relation) {
+        let mut subject_types = HashSet::new();
+        for subject_type in &relation.subject_types {
+            let described = subject_type.to_string();
+            if !subject_types.insert(described.clone()) {
+                self.error(
+                    format!(
+                        "duplicate subject type `{described}` on relation `{}`",
+                        relation.name
+                    ),
+                    subject_type.span,
+                );
+            }
+
+            let name = subject_type.definition.as_str();
+            match self.definitions.get(name) {
+                None => self.error(
+                    format!("object definition `{name}` not found"),
+                    subject_type.definition.span,
+                ),
+                Some(target) => {
+                    if let SubjectTypeKind::Relation(subject_relation) = &subject_type.kind {
+                        if target.member(subject_relation).is_none() {
+                            self.error(
+                                format!(
+                                    "relation/permission `{subject_relation}` not found under \
+                                     definition `{name}`"
+                                ),
+                                subject_type.span,
+                            );
+                        }
+                    }
+                }
+            }
+
+            if let Some(caveat) = &subject_type.caveat {
+                if !self.caveats.contains_key(caveat.as_str()) {
+                    self.error(format!("caveat `{caveat}` not found"), caveat.span);
+                }
+            }
+            if subject_type.expiration && !self.expiration {
+                self.error(
+                    "expiration is not enabled, add `use expiration` to the schema".to_string(),
+                    subject_type.span,
+                );
+            }
+        }
+    }
+
+    fn check_expr(&mut self, definition: &'a Definition, permission: &Permission, expr: &Expr) {
+        let definition_name = &definition.name;
+        match expr {
+            Expr::Nil(_) => {}
+            Expr::Ref(name) => {
+                if definition.member(name.as_str()).is_none() {
+                    self.error(
+                        format!(
+                            "relation/permission `{name}` not found under definition \
+                             `{definition_name}`"
+                        ),
+                        name.span,
+                    );
+                }
+            }
+            Expr::Arrow {
+                tupleset, computed, ..
+            } => {
+                let relation = match definition.member(tupleset.as_str()) {
+                    Some(Member::Relation(relation)) => relation,
+                    Some(Member::Permission(_)) => {
+                        return self.error(
+                            format!(
+                                "permission `{tupleset}` cannot be used on the left side of \
+                                 the arrow in permission `{}`, only relations can",
+                                permission.name
+                            ),
+                            tupleset.span,
+                        )
+                    }
+                    None => {
+                        return self.error(
+                            format!(
+                                "relation/permission `{tupleset}` not found under definition \
+                                 `{definition_name}`"
+                            ),
+                            tupleset.span,
+                        )
+                    }
+                };
+
+                let mut found = false;
+                let mut checked = false;
+                for subject_type in &relation.subject_types {
+                    if subject_type.kind == SubjectTypeKind::Wildcard {
+                        self.error(
+                            format!(
+                                "relation `{tupleset}` used in an arrow in permission `{}` \
+                                 includes the wildcard `{subject_type}`",
+                                permission.name
+                            ),
+                            tupleset.span,
+                        );
+                    }
+                    if let Some(target) = self.definitions.get(subject_type.definition.as_str()) {
+                        checked = true;
+                        found |= target.member(computed.as_str()).is_some();
+                    }
+                }
+                if checked && !found {
+                    self.error(
+                        format!(
+                            "relation/permission `{computed}` not found on any of the subject \
+                             types of relation `{tupleset}` under definition `{definition_name}`"
+                        ),
+                        computed.span,
+                    );
+                }
+            }
+            Expr::Union { operands, .. } | Expr::Intersection { operands, .. } => {
+                for operand in operands {
+                    self.check_expr(definition, permission, operand);
+                }
+            }
+            Expr::Exclusion { base, excluded, .. } => {
+                self.check_expr(definition, permission, base);
+                self.check_expr(definition, permission, excluded);
+            }
+        }
+    }
+
+    fn check_caveat(&mut self, caveat: &Caveat) {
+        if caveat.parameters.is_empty() {
+            self.error(
+                format!("caveat `{}` must have at least one parameter", caveat.name),
+                caveat.name.span,
+            );
+        }
+        let mut names = HashSet::new();
+        for parameter in &caveat.parameters {
+            if !names.insert(parameter.name.as_str()) {
+                self.error(
+                    format!(
+                        "duplicate parameter `{}` of caveat `{}`",
+                        parameter.name, caveat.name
+                    ),
+                    parameter.name.span,
+                );
+            }
+            if let Some(unknown) = unknown_type(&parameter.parameter_type) {
+                self.error(
+                    format!(
+                        "unknown type `{unknown}` of parameter `{}` of caveat `{}`",
+                        parameter.name, caveat.name
+                    ),
+                    parameter.type_span,
+                );
+            }
+        }
+    }
+
+    /// Warn about relations and caveats that are never referenced.
+    fn lint_unused(&mut self) {
+        let mut used_members = HashSet::new();
+        let mut used_caveats = HashSet::new();
+        for definition in self.definitions.values().copied() {
+            for relation in definition.relations() {
+                for subject_type in &relation.subject_types {
+                    if let SubjectTypeKind::Relation(subject_relation) = &subject_type.kind {
+                        used_members
+                            .insert((subject_type.definition.as_str(), subject_relation.as_str()));
+                    }
+                    if let Some(caveat) = &subject_type.caveat {
+                        used_caveats.insert(caveat.as_str());
+                    }
+                }
+            }
+            for permission in definition.permissions() {
+                self.collect_used(definition, &permission.expression, &mut used_members);
+            }
+        }
+
+        for definition in self.schema.definitions() {
+            for relation in definition.relations() {
+                let key = (definition.name.as_str(), relation.name.as_str());
+                if !used_members.contains(&key) {
+                    self.warning(
+                        format!(
+                            "relation `{}` under definition `{}` is not used by any permission",
+                            relation.name, definition.name
+                        ),
+                        relation.name.span,
+                    );
+                }
+            }
+        }
+        for caveat in self.schema.caveats() {
+            if !used_caveats.contains(caveat.name.as_str()) {
+                self.warning(
+                    format!("caveat `{}` is not used by any relation", caveat.name),
+                    caveat.name.span,
+                );
+            }
+        }
+    }
+
+    /// Collect the relations and permissions referenced by `expr`, including
+    /// those of the subject types of arrows.
+    fn collect_used(
+        &self,
+        definition: &'a Definition,
+        expr: &'a Expr,
+        used: &mut HashSet<(&'a str, &'a str)>,
+    ) {
+        let name = definition.name.as_str();
+        match expr {
+            Expr::Nil(_) => {}
+            Expr::Ref(member) => {
+                used.insert((name, member.as_str()));
+            }
+            Expr::Arrow {
+                tupleset, computed, ..
+            } => {
+                used.insert((name, tupleset.as_str()));
+                for relation in definition.relations() {
+                    if relation.name != *tupleset {
+                        continue;
+                    }
+                    for subject_type in &relation.subject_types {
+                        used.insert((subject_type.definition.as_str(), computed.as_str()));
+                    }
+                }
+            }
+            Expr::Union { operands, .. } | Expr::Intersection { operands, .. } => {
+                for operand in operands {
+                    self.collect_used(definition, operand, used);
+                }
+            }
+            Expr::Exclusion { base, excluded, .. } => {
+                self.collect_used(definition, base, used);
+                self.collect_used(definition, excluded, used);
+            }
+        }
+    }
+
+    /// Warn about permissions that can never contain a subject, such as
+    /// `nil`, intersections with such permissions, or permissions that only
+    /// refer to themselves.
+    fn lint_empty_permissions(&mut self) {
+        // The permissions that may contain a subject, as a least fixpoint.
+        let mut non_empty = HashSet::new();
+        loop {
+            let mut changed = false;
+            for definition in self.definitions.values().copied() {
+                for permission in definition.permissions() {
+                    let key = (definition.name.as_str(), permission.name.as_str());
+                    if !non_empty.contains(&key)
+                        && self.may_be_non_empty(definition, &permission.expression, &non_empty)
+                    {
+                        non_empty.insert(key);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        for definition in self.schema.definitions() {
+            for permission in definition.permissions() {
+                let key = (definition.name.as_str(), permission.name.as_str());
+                if !non_empty.contains(&key) {
+                    self.warning(
+                        format!(
+                            "permission `{}` under definition `{}` is always empty",
+                            permission.name, definition.name
+                        ),
+                        permission.name.span,
+                    );
+                }
+            }
+        }
+    }
+
+    fn may_be_non_empty(
+        &self,
+        definition: &Definition,
+        expr: &Expr,
+        non_empty: &HashSet<(&str, &str)>,
+    ) -> bool {
+        let member_may_be_non_empty = |definition: &Definition, name: &str| match definition
+            .member(name)
+        {
+            Some(Member::Permission(_)) => non_empty.contains(&(definition.name.as_str(), name)),
+            // Undefined members are reported as errors.
+            Some(Member::Relation(_)) | None => true,
+        };
+
+        match expr {
+            Expr::Nil(_) => false,
+            Expr::Ref(name) => member_may_be_non_empty(definition, name.as_str()),
+            Expr::Arrow {
+                tupleset, computed, ..
+            } => {
+                let Some(Member::Relation(relation)) = definition.member(tupleset.as_str()) else {
+                    return true;
+                };
+                relation.subject_types.iter().any(|subject_type| {
+                    match self.definitions.get(subject_type.definition.as_str()) {
+                        Some(target) => {
+                            target.member(computed.as_str()).is_some()
+                                && member_may_be_non_empty(target, computed.as_str())
+                        }
+                        None => true,
+                    }
+                })
+            }
+            Expr::Union { operands, .. } => operands
+                .iter()
+                .any(|operand| self.may_be_non_empty(definition, operand, non_empty)),
+            Expr::Intersection { operands, .. } => operands
+                .iter()
+                .all(|operand| self.may_be_non_empty(definition, operand, non_empty)),
+            Expr::Exclusion { base, .. } => self.may_be_non_empty(definition, base, non_empty),
+        }
+    }
+}
+
+/// The unknown type in `parameter_type`, if any.
+fn unknown_type(parameter_type: &CaveatParameterType) -> Option<&str> {
+    match parameter_type {
+        CaveatParameterType::Unknown(type_name) => Some(type_name),
+        CaveatParameterType::List(inner) | CaveatParameterType::Map(inner) => unknown_type(inner),
+        _ => None,
+    }
+}
+
+/// Whether `name` has between `min` and `max` characters, starts with a
+/// lowercase letter, ends with a lowercase letter or digit, and otherwise
+/// contains lowercase letters, digits and underscores.
+fn is_identifier(name: &str, min: usize, max: usize) -> bool {
+    let bytes = name.as_bytes();
+    let last = bytes.last().copied().unwrap_or_default();
+    (min..=max).contains(&bytes.len())
+        && bytes[0].is_ascii_lowercase()
+        && (last.is_ascii_lowercase() || last.is_ascii_digit())
+        && bytes
+            .iter()
+            .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
+
+fn is_definition_name(name: &str) -> bool {
+    let (prefixes, name) = match name.rsplit_once('/') {
+        Some((prefixes, name)) => (Some(prefixes), name),
+        None => (None, name),
+    };
+    is_identifier(name, 3, 64)
+        && prefixes.is_none_or(|prefixes| {
+            prefixes
+                .split('/')
+                .all(|prefix| is_identifier(prefix, 3, 63))
+        })
+}
+
+fn is_relation_name(name: &str) -> bool {
+    is_identifier(name, 3, 64)
+}
+
+fn is_caveat_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    (1..=128).contains(&name.len())
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/_|-".contains(c))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::parse;
+    use super::*;
+
+    fn diagnostics(text: &str) -> Vec<String> {
+        check(&parse(text).unwrap())
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_schema() {
+        let schema = r#"
+use expiration
+
+caveat on_weekday(day int, allowed list<string>) {
+    day < 6
+}
+
+definition user {}
+
+definition tenant/group {
+    relation member: user | tenant/group#member with expiration
+}
+
+definition document {
+    relation parent: document
+    relation viewer: user | user:* | tenant/group#member with on_weekday and expiration
+    relation banned: user
+    permission view = (viewer + parent->view) - banned
+    permission view_all = viewer & parent.all(view)
+}
+"#;
+        assert_eq!(diagnostics(schema), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_errors() {
+        let schema = r#"
+caveat on_weekday(day weekday, allowed map<timezone>, day int) {
+    day < 6
+}
+caveat c() { true }
+
+definition user {}
+definition user {}
+definition Team {}
+
+definition document {
+    relation parent: document | folder | user:*
+    relation viewer: user | user | group#member | user#manager with on_weekday | user with ip
+    relation expiring: user with expiration
+    relation x: user
+    permission view = viewer + editor + parent->view
+    permission edit = view->edit + parent->missing
+    permission view = viewer
+}
+"#;
+        assert_eq!(
+            diagnostics(schema),
+            [
+                "error: unknown type `weekday` of parameter `day` of caveat `on_weekday`",
+                "error: unknown type `timezone` of parameter `allowed` of caveat `on_weekday`",
+                "error: duplicate parameter `day` of caveat `on_weekday`",
+                "error: caveat `c` must have at least one parameter",
+                "warning: caveat `c` is not used by any relation",
+                "error: duplicate definition `user`",
+                "error: definition name `Team` must match \
+                 `^([a-z][a-z0-9_]{1,61}[a-z0-9]/)*[a-z][a-z0-9_]{1,62}[a-z0-9]$`",
+                "error: object definition `folder` not found",
+                "error: duplicate subject type `user` on relation `viewer`",
+                "error: object definition `group` not found",
+                "error: relation/permission `manager` not found under definition `user`",
+                "error: caveat `ip` not found",
+                "warning: relation `expiring` under definition `document` is not used by any \
+                 permission",
+                "error: expiration is not enabled, add `use expiration` to the schema",
+                "error: relation or permission name `x` must match \
+                 `^[a-z][a-z0-9_]{1,62}[a-z0-9]$`",
+                "warning: relation `x` under definition `document` is not used by any permission",
+                "error: relation/permission `editor` not found under definition `document`",
+                "error: relation `parent` used in an arrow in permission `view` includes the \
+                 wildcard `user:*`",
+                "error: permission `view` cannot be used on the left side of the arrow in \
+                 permission `edit`, only relations can",
+                "error: relation `parent` used in an arrow in permission `edit` includes the \
+                 wildcard `user:*`",
+                "error: relation/permission `missing` not found on any of the subject types of \
+                 relation `parent` under definition `document`",
+                "error: duplicate relation or permission `view` under definition `document`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lints() {
+        let schema = r#"
+definition user {}
+
+definition folder {
+    relation viewer: user
+    relation unused: user
+    permission view = viewer
+    permission nothing = nil
+}
+
+definition document {
+    relation parent: folder
+    relation viewer: user
+    permission view = viewer + parent->view
+    permission recursive = recursive + parent->nothing
+    permission both = viewer & nil
+    permission excluded = nil - viewer
+    permission through = parent->view & view
+}
+"#;
+        assert_eq!(
+            diagnostics(schema),
+            [
+                "warning: relation `unused` under definition `folder` is not used by any \
+                 permission",
+                "warning: permission `nothing` under definition `folder` is always empty",
+                "warning: permission `recursive` under definition `document` is always empty",
+                "warning: permission `both` under definition `document` is always empty",
+                "warning: permission `excluded` under definition `document` is always empty",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        assert!(is_definition_name("tenant/sub_tenant/document"));
+        assert!(!is_definition_name("tenant/document_"));
+        assert!(!is_definition_name("te/document"));
+        assert!(!is_definition_name(&"d".repeat(65)));
+        assert!(is_relation_name("view_2"));
+        assert!(!is_relation_name("2view"));
+        assert!(is_caveat_name("tenant/on-weekday"));
+        assert!(!is_caveat_name("-weekday"));
+    }
+}
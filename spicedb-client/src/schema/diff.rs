@@ -0,0 +1,393 @@
+use std::fmt;
+
+use spicedb_grpc::authzed::api::v1::{
+    exp_schema_diff::Diff, ExpCaveatParameter, ExpRelationSubjectTypeChange, ExpSchemaDiff,
+    ExperimentalDiffSchemaResponse, ZedToken,
+};
+
+use super::{
+    Caveat, CaveatParameter, CaveatParameterType, Definition, Permission, Relation, SubjectType,
+};
+
+/// Changes from the schema stored in SpiceDB to another schema, returned by
+/// [`SpicedbClient::diff_schema`](crate::SpicedbClient::diff_schema).
+///
+/// ```rust
+/// # use spicedb_client::schema::SchemaDiff;
+/// #
+/// # fn check(diff: SchemaDiff) {
+/// for change in diff.breaking_changes() {
+///     eprintln!("breaking change: {change}");
+/// }
+/// assert!(!diff.is_breaking());
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SchemaDiff {
+    pub changes: Vec<SchemaChange>,
+    /// The ZedToken at which the stored schema was read.
+    pub read_at: Option<ZedToken>,
+}
+
+impl SchemaDiff {
+    /// Whether any change is breaking, see [`SchemaChange::is_breaking`].
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(SchemaChange::is_breaking)
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes.iter().filter(|change| change.is_breaking())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl From<ExperimentalDiffSchemaResponse> for SchemaDiff {
+    fn from(response: ExperimentalDiffSchemaResponse) -> Self {
+        Self {
+            changes: response.diffs.into_iter().map(SchemaChange::from).collect(),
+            read_at: response.read_at,
+        }
+    }
+}
+
+/// A single change between two schemas.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaChange {
+    DefinitionAdded(Definition),
+    DefinitionRemoved(Definition),
+    DefinitionCommentChanged(Definition),
+    RelationAdded(Relation),
+    RelationRemoved(Relation),
+    RelationCommentChanged(Relation),
+    SubjectTypeAdded {
+        relation: Relation,
+        subject_type: SubjectType,
+    },
+    SubjectTypeRemoved {
+        relation: Relation,
+        subject_type: SubjectType,
+    },
+    PermissionAdded(Permission),
+    PermissionRemoved(Permission),
+    PermissionCommentChanged(Permission),
+    PermissionExpressionChanged(Permission),
+    CaveatAdded(Caveat),
+    CaveatRemoved(Caveat),
+    CaveatCommentChanged(Caveat),
+    CaveatExpressionChanged(Caveat),
+    CaveatParameterAdded {
+        caveat: String,
+        parameter: CaveatParameter,
+    },
+    CaveatParameterRemoved {
+        caveat: String,
+        parameter: CaveatParameter,
+    },
+    CaveatParameterTypeChanged {
+        caveat: String,
+        parameter: CaveatParameter,
+        previous_type: CaveatParameterType,
+    },
+    /// A diff this client does not recognize, such as a new kind of change
+    /// sent by a newer server, or a diff with missing fields.
+    Unknown(ExpSchemaDiff),
+}
+
+impl SchemaChange {
+    /// Whether the change can break existing relationships or requests: it
+    /// removes something they may refer to, or changes the type of a caveat
+    /// parameter they may provide.
+    ///
+    /// Changes to permission and caveat expressions change who has access,
+    /// but are not considered breaking. Unknown changes are considered
+    /// breaking.
+    pub fn is_breaking(&self) -> bool {
+        matches!(
+            self,
+            SchemaChange::DefinitionRemoved(_)
+                | SchemaChange::RelationRemoved(_)
+                | SchemaChange::SubjectTypeRemoved { .. }
+                | SchemaChange::PermissionRemoved(_)
+                | SchemaChange::CaveatRemoved(_)
+                | SchemaChange::CaveatParameterRemoved { .. }
+                | SchemaChange::CaveatParameterTypeChanged { .. }
+                | SchemaChange::Unknown(_)
+        )
+    }
+}
+
+impl From<ExpSchemaDiff> for SchemaChange {
+    fn from(diff: ExpSchemaDiff) -> Self {
+        let subject_type_change = |change: ExpRelationSubjectTypeChange| {
+            Some((change.relation?.into(), change.changed_subject_type?.into()))
+        };
+        let parameter = |parameter: ExpCaveatParameter| {
+            (parameter.parent_caveat_name.clone(), parameter.into())
+        };
+
+        let Some(change) = diff.diff.clone() else {
+            return SchemaChange::Unknown(diff);
+        };
+        match change {
+            Diff::DefinitionAdded(definition) => SchemaChange::DefinitionAdded(definition.into()),
+            Diff::DefinitionRemoved(definition) => {
+                SchemaChange::DefinitionRemoved(definition.into())
+            }
+            Diff::DefinitionDocCommentChanged(definition) => {
+                SchemaChange::DefinitionCommentChanged(definition.into())
+            }
+            Diff::RelationAdded(relation) => SchemaChange::RelationAdded(relation.into()),
+            Diff::RelationRemoved(relation) => SchemaChange::RelationRemoved(relation.into()),
+            Diff::RelationDocCommentChanged(relation) => {
+                SchemaChange::RelationCommentChanged(relation.into())
+            }
+            Diff::RelationSubjectTypeAdded(change) => {
+                let Some((relation, subject_type)) = subject_type_change(change) else {
+                    return SchemaChange::Unknown(diff);
+                };
+                SchemaChange::SubjectTypeAdded {
+                    relation,
+                    subject_type,
+                }
+            }
+            Diff::RelationSubjectTypeRemoved(change) => {
+                let Some((relation, subject_type)) = subject_type_change(change) else {
+                    return SchemaChange::Unknown(diff);
+                };
+                SchemaChange::SubjectTypeRemoved {
+                    relation,
+                    subject_type,
+                }
+            }
+            Diff::PermissionAdded(permission) => SchemaChange::PermissionAdded(permission.into()),
+            Diff::PermissionRemoved(permission) => {
+                SchemaChange::PermissionRemoved(permission.into())
+            }
+            Diff::PermissionDocCommentChanged(permission) => {
+                SchemaChange::PermissionCommentChanged(permission.into())
+            }
+            Diff::PermissionExprChanged(permission) => {
+                SchemaChange::PermissionExpressionChanged(permission.into())
+            }
+            Diff::CaveatAdded(caveat) => SchemaChange::CaveatAdded(caveat.into()),
+            Diff::CaveatRemoved(caveat) => SchemaChange::CaveatRemoved(caveat.into()),
+            Diff::CaveatDocCommentChanged(caveat) => {
+                SchemaChange::CaveatCommentChanged(caveat.into())
+            }
+            Diff::CaveatExprChanged(caveat) => SchemaChange::CaveatExpressionChanged(caveat.into()),
+            Diff::CaveatParameterAdded(added) => {
+                let (caveat, parameter) = parameter(added);
+                SchemaChange::CaveatParameterAdded { caveat, parameter }
+            }
+            Diff::CaveatParameterRemoved(removed) => {
+                let (caveat, parameter) = parameter(removed);
+                SchemaChange::CaveatParameterRemoved { caveat, parameter }
+            }
+            Diff::CaveatParameterTypeChanged(change) => {
+                let Some(changed) = change.parameter else {
+                    return SchemaChange::Unknown(diff);
+                };
+                let (caveat, parameter) = parameter(changed);
+                SchemaChange::CaveatParameterTypeChanged {
+                    caveat,
+                    parameter,
+                    previous_type: CaveatParameterType::parse(&change.previous_type),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaChange::DefinitionAdded(definition) => {
+                write!(f, "definition `{}` added", definition.name)
+            }
+            SchemaChange::DefinitionRemoved(definition) => {
+                write!(f, "definition `{}` removed", definition.name)
+            }
+            SchemaChange::DefinitionCommentChanged(definition) => {
+                write!(f, "comment of definition `{}` changed", definition.name)
+            }
+            SchemaChange::RelationAdded(relation) => {
+                write!(f, "relation `{}#{}` added", relation.definition, relation.name)
+            }
+            SchemaChange::RelationRemoved(relation) => {
+                write!(f, "relation `{}#{}` removed", relation.definition, relation.name)
+            }
+            SchemaChange::RelationCommentChanged(relation) => write!(
+                f,
+                "comment of relation `{}#{}` changed",
+                relation.definition, relation.name
+            ),
+            SchemaChange::SubjectTypeAdded {
+                relation,
+                subject_type,
+            } => write!(
+                f,
+                "subject type `{subject_type}` added to relation `{}#{}`",
+                relation.definition, relation.name
+            ),
+            SchemaChange::SubjectTypeRemoved {
+                relation,
+                subject_type,
+            } => write!(
+                f,
+                "subject type `{subject_type}` removed from relation `{}#{}`",
+                relation.definition, relation.name
+            ),
+            SchemaChange::PermissionAdded(permission) => write!(
+                f,
+                "permission `{}#{}` added",
+                permission.definition, permission.name
+            ),
+            SchemaChange::PermissionRemoved(permission) => write!(
+                f,
+                "permission `{}#{}` removed",
+                permission.definition, permission.name
+            ),
+            SchemaChange::PermissionCommentChanged(permission) => write!(
+                f,
+                "comment of permission `{}#{}` changed",
+                permission.definition, permission.name
+            ),
+            SchemaChange::PermissionExpressionChanged(permission) => write!(
+                f,
+                "expression of permission `{}#{}` changed",
+                permission.definition, permission.name
+            ),
+            SchemaChange::CaveatAdded(caveat) => write!(f, "caveat `{}` added", caveat.name),
+            SchemaChange::CaveatRemoved(caveat) => write!(f, "caveat `{}` removed", caveat.name),
+            SchemaChange::CaveatCommentChanged(caveat) => {
+                write!(f, "comment of caveat `{}` changed", caveat.name)
+            }
+            SchemaChange::CaveatExpressionChanged(caveat) => {
+                write!(f, "expression of caveat `{}` changed", caveat.name)
+            }
+            SchemaChange::CaveatParameterAdded { caveat, parameter } => write!(
+                f,
+                "parameter `{}` added to caveat `{caveat}`",
+                parameter.name
+            ),
+            SchemaChange::CaveatParameterRemoved { caveat, parameter } => write!(
+                f,
+                "parameter `{}` removed from caveat `{caveat}`",
+                parameter.name
+            ),
+            SchemaChange::CaveatParameterTypeChanged {
+                caveat,
+                parameter,
+                previous_type,
+            } => write!(
+                f,
+                "type of parameter `{}` of caveat `{caveat}` changed from `{previous_type}` to `{}`",
+                parameter.name, parameter.parameter_type
+            ),
+            SchemaChange::Unknown(_) => f.write_str("unrecognized schema change"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use spicedb_grpc::authzed::api::v1::{
+        exp_type_reference::Typeref, ExpCaveatParameterTypeChange, ExpDefinition, ExpRelation,
+        ExpTypeReference,
+    };
+
+    use super::*;
+
+    fn diff(diff: Diff) -> ExpSchemaDiff {
+        ExpSchemaDiff { diff: Some(diff) }
+    }
+
+    #[test]
+    fn test_from_response() {
+        let relation = ExpRelation {
+            name: "viewer".to_string(),
+            parent_definition_name: "document".to_string(),
+            ..Default::default()
+        };
+        let response = ExperimentalDiffSchemaResponse {
+            diffs: vec![
+                diff(Diff::DefinitionAdded(ExpDefinition {
+                    name: "folder".to_string(),
+                    ..Default::default()
+                })),
+                diff(Diff::RelationSubjectTypeRemoved(
+                    ExpRelationSubjectTypeChange {
+                        relation: Some(relation),
+                        changed_subject_type: Some(ExpTypeReference {
+                            subject_definition_name: "group".to_string(),
+                            typeref: Some(Typeref::OptionalRelationName("member".to_string())),
+                            ..Default::default()
+                        }),
+                    },
+                )),
+                diff(Diff::CaveatParameterTypeChanged(
+                    ExpCaveatParameterTypeChange {
+                        parameter: Some(ExpCaveatParameter {
+                            name: "limit".to_string(),
+                            r#type: "uint".to_string(),
+                            parent_caveat_name: "quota".to_string(),
+                        }),
+                        previous_type: "int".to_string(),
+                    },
+                )),
+                ExpSchemaDiff { diff: None },
+            ],
+            read_at: None,
+        };
+        let diff = SchemaDiff::from(response);
+
+        let changes: Vec<_> = diff.changes.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            changes,
+            [
+                "definition `folder` added",
+                "subject type `group#member` removed from relation `document#viewer`",
+                "type of parameter `limit` of caveat `quota` changed from `int` to `uint`",
+                "unrecognized schema change",
+            ]
+        );
+        assert!(diff.is_breaking());
+        assert_eq!(diff.breaking_changes().count(), 3);
+        assert!(!diff.changes[0].is_breaking());
+    }
+
+    #[test]
+    fn test_unknown_change() {
+        // A diff kind unknown to this client decodes without a change, and a
+        // subject type change may be missing its relation.
+        let response = ExperimentalDiffSchemaResponse {
+            diffs: vec![
+                diff(Diff::DefinitionAdded(ExpDefinition {
+                    name: "folder".to_string(),
+                    ..Default::default()
+                })),
+                ExpSchemaDiff { diff: None },
+                diff(Diff::RelationSubjectTypeAdded(
+                    ExpRelationSubjectTypeChange {
+                        relation: None,
+                        changed_subject_type: Some(ExpTypeReference::default()),
+                    },
+                )),
+            ],
+            read_at: None,
+        };
+        let diff = SchemaDiff::from(response);
+
+        assert_eq!(diff.changes.len(), 3);
+        assert_eq!(
+            diff.changes[1],
+            SchemaChange::Unknown(ExpSchemaDiff { diff: None })
+        );
+        assert!(matches!(diff.changes[2], SchemaChange::Unknown(_)));
+        assert!(diff.is_breaking());
+        assert_eq!(diff.breaking_changes().count(), 2);
+    }
+}
@@ -0,0 +1,548 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+
+use super::ast::{
+    ArrowFunction, Caveat, CaveatParameter, Definition, Expr, Item, Member, Permission, Relation,
+    Schema, SubjectType,
+};
+use super::parser::parse_with_comments;
+use super::{parse, ParseError, SubjectTypeKind};
+
+/// Format a schema in the canonical style of SpiceDB, keeping the order of
+/// the definitions, caveats, relations and permissions.
+///
+/// Definitions are indented with tabs and separated by blank lines, nested
+/// operations of a permission are parenthesized, and consecutive unions and
+/// intersections are merged.
+///
+/// Comments are kept on their own lines before and after definitions,
+/// caveats, relations and permissions, and at the end of their lines. Other
+/// comments, such as comments inside a permission expression, cannot be
+/// kept and are an error.
+///
+/// ```rust
+/// # use spicedb_client::schema::format;
+/// #
+/// let schema = format("definition user {}  definition document{relation viewer:user}").unwrap();
+/// assert_eq!(
+///     schema,
+///     "definition user {}\n\ndefinition document {\n\trelation viewer: user\n}"
+/// );
+/// ```
+pub fn format(text: &str) -> Result<String, ParseError> {
+    Ok(parse_with_comments(text)?.to_string())
+}
+
+/// Whether two schemas define the same definitions, caveats, relations and
+/// permissions, regardless of comments, formatting and the order of items,
+/// subject types and union and intersection operands.
+///
+/// This can be used to skip writing a schema that is already stored.
+///
+/// ```rust
+/// # use spicedb_client::schema::schema_semantically_equal;
+/// #
+/// let stored = "definition user {}\ndefinition document {\n\trelation viewer: user\n}";
+/// let schema = "// Documents\ndefinition document { relation viewer: user }\ndefinition user {}";
+/// assert!(schema_semantically_equal(stored, schema).unwrap());
+/// ```
+pub fn schema_semantically_equal(a: &str, b: &str) -> Result<bool, ParseError> {
+    Ok(Canonical::from(&parse(a)?) == Canonical::from(&parse(b)?))
+}
+
+impl Display for Schema {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_comment(f, &self.comment, "")?;
+        for (index, item) in self.items.iter().enumerate() {
+            if index > 0 {
+                f.write_str("\n\n")?;
+            }
+            match item {
+                Item::Use(flag) => write!(f, "use {flag}")?,
+                Item::Caveat(caveat) => caveat.fmt(f)?,
+                Item::Definition(definition) => definition.fmt(f)?,
+            }
+        }
+        if !self.end_comment.is_empty() {
+            if !self.items.is_empty() {
+                f.write_str("\n\n")?;
+            }
+            write_comment(f, &self.end_comment, "")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Definition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_comment(f, &self.comment, "")?;
+        write!(f, "definition {} {{", self.name)?;
+        if !self.members.is_empty() || !self.end_comment.is_empty() {
+            for (index, member) in self.members.iter().enumerate() {
+                f.write_str("\n")?;
+                // Commented members are separated by a blank line.
+                if index > 0 && !member.comment().is_empty() {
+                    f.write_str("\n")?;
+                }
+                write_comment(f, member.comment(), "\t")?;
+                match member {
+                    Member::Relation(relation) => write!(f, "\t{relation}")?,
+                    Member::Permission(permission) => write!(f, "\t{permission}")?,
+                }
+                write_trailing_comment(f, member.trailing_comment())?;
+            }
+            f.write_str("\n")?;
+            write_comment(f, &self.end_comment, "\t")?;
+        }
+        f.write_str("}")?;
+        write_trailing_comment(f, &self.trailing_comment)
+    }
+}
+
+/// The relation without its comments.
+impl Display for Relation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "relation {}: ", self.name)?;
+        for (index, subject_type) in self.subject_types.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" | ")?;
+            }
+            subject_type.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SubjectType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.definition.fmt(f)?;
+        match &self.kind {
+            SubjectTypeKind::Object => {}
+            SubjectTypeKind::Relation(relation) => write!(f, "#{relation}")?,
+            SubjectTypeKind::Wildcard => f.write_str(":*")?,
+        }
+        match (&self.caveat, self.expiration) {
+            (Some(caveat), true) => write!(f, " with {caveat} and expiration"),
+            (Some(caveat), false) => write!(f, " with {caveat}"),
+            (None, true) => f.write_str(" with expiration"),
+            (None, false) => Ok(()),
+        }
+    }
+}
+
+/// The permission without its comments.
+impl Display for Permission {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "permission {} = {}", self.name, self.expression)
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let write_operand = |f: &mut Formatter<'_>, operand: &Expr| match operand {
+            Expr::Union { .. } | Expr::Intersection { .. } | Expr::Exclusion { .. } => {
+                write!(f, "({operand})")
+            }
+            _ => operand.fmt(f),
+        };
+        let write_operands = |f: &mut Formatter<'_>, operands: Vec<&Expr>, operator: &str| {
+            for (index, operand) in operands.into_iter().enumerate() {
+                if index > 0 {
+                    f.write_str(operator)?;
+                }
+                write_operand(f, operand)?;
+            }
+            Ok(())
+        };
+
+        match self {
+            Self::Nil(_) => f.write_str("nil"),
+            Self::Ref(name) => name.fmt(f),
+            Self::Arrow {
+                tupleset,
+                function,
+                computed,
+                ..
+            } => match function {
+                ArrowFunction::Arrow => write!(f, "{tupleset}->{computed}"),
+                ArrowFunction::Any => write!(f, "{tupleset}.any({computed})"),
+                ArrowFunction::All => write!(f, "{tupleset}.all({computed})"),
+            },
+            Self::Union { .. } => write_operands(f, self.flattened_operands(), " + "),
+            Self::Intersection { .. } => write_operands(f, self.flattened_operands(), " & "),
+            Self::Exclusion { base, excluded, .. } => {
+                write_operand(f, base)?;
+                f.write_str(" - ")?;
+                write_operand(f, excluded)
+            }
+        }
+    }
+}
+
+impl Expr {
+    /// Operands of a union or intersection, with the operands of nested
+    /// operations of the same kind merged in.
+    fn flattened_operands(&self) -> Vec<&Expr> {
+        let mut flattened = Vec::new();
+        let (Self::Union { operands, .. } | Self::Intersection { operands, .. }) = self else {
+            return flattened;
+        };
+        for operand in operands {
+            if std::mem::discriminant(operand) == std::mem::discriminant(self) {
+                flattened.extend(operand.flattened_operands());
+            } else {
+                flattened.push(operand);
+            }
+        }
+        flattened
+    }
+}
+
+impl Display for Caveat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_comment(f, &self.comment, "")?;
+        write!(f, "caveat {}(", self.name)?;
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            parameter.fmt(f)?;
+        }
+        f.write_str(") {\n")?;
+        for line in self.expression.lines() {
+            match line.is_empty() {
+                true => f.write_str("\n")?,
+                false => writeln!(f, "\t{line}")?,
+            }
+        }
+        f.write_str("}")?;
+        write_trailing_comment(f, &self.trailing_comment)
+    }
+}
+
+impl Display for CaveatParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.parameter_type)
+    }
+}
+
+/// Write the lines of a comment with `indent`, aligning the continuation
+/// lines of block comments.
+fn write_comment(f: &mut Formatter<'_>, comment: &str, indent: &str) -> fmt::Result {
+    for line in comment.lines() {
+        let line = line.trim();
+        match line.starts_with('*') {
+            true => writeln!(f, "{indent} {line}")?,
+            false => writeln!(f, "{indent}{line}")?,
+        }
+    }
+    Ok(())
+}
+
+fn write_trailing_comment(f: &mut Formatter<'_>, comment: &str) -> fmt::Result {
+    match comment.is_empty() {
+        true => Ok(()),
+        false => write!(f, " {comment}"),
+    }
+}
+
+/// Order-independent model of a schema, without comments.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Canonical {
+    flags: BTreeSet<String>,
+    /// Parameters and expression of each caveat.
+    caveats: BTreeMap<String, (BTreeMap<String, String>, String)>,
+    /// Subject types of each relation, by definition.
+    relations: BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+    /// Expression of each permission, by definition.
+    permissions: BTreeMap<String, BTreeMap<String, CanonicalExpr>>,
+}
+
+impl From<&Schema> for Canonical {
+    fn from(schema: &Schema) -> Self {
+        let mut canonical = Canonical::default();
+        for item in &schema.items {
+            match item {
+                Item::Use(flag) => {
+                    canonical.flags.insert(flag.to_string());
+                }
+                Item::Caveat(caveat) => {
+                    let parameters = caveat
+                        .parameters
+                        .iter()
+                        .map(|parameter| {
+                            let parameter_type = parameter.parameter_type.to_string();
+                            (parameter.name.to_string(), parameter_type)
+                        })
+                        .collect();
+                    let expression = strip_whitespace(&caveat.expression);
+                    canonical
+                        .caveats
+                        .insert(caveat.name.to_string(), (parameters, expression));
+                }
+                Item::Definition(definition) => {
+                    let name = definition.name.to_string();
+                    let relations = definition
+                        .relations()
+                        .map(|relation| {
+                            let subject_types = relation
+                                .subject_types
+                                .iter()
+                                .map(ToString::to_string)
+                                .collect();
+                            (relation.name.to_string(), subject_types)
+                        })
+                        .collect();
+                    let permissions = definition
+                        .permissions()
+                        .map(|permission| {
+                            let expression = CanonicalExpr::from(&permission.expression);
+                            (permission.name.to_string(), expression)
+                        })
+                        .collect();
+                    canonical.relations.insert(name.clone(), relations);
+                    canonical.permissions.insert(name, permissions);
+                }
+            }
+        }
+        canonical
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum CanonicalExpr {
+    Nil,
+    Ref(String),
+    /// `tupleset->computed`, which is the same as `tupleset.any(computed)`,
+    /// or `tupleset.all(computed)` if `all` is set.
+    Arrow {
+        tupleset: String,
+        computed: String,
+        all: bool,
+    },
+    Union(BTreeSet<CanonicalExpr>),
+    Intersection(BTreeSet<CanonicalExpr>),
+    Exclusion(Box<CanonicalExpr>, Box<CanonicalExpr>),
+}
+
+impl From<&Expr> for CanonicalExpr {
+    fn from(expr: &Expr) -> Self {
+        let operands = |expr: &Expr| {
+            expr.flattened_operands()
+                .into_iter()
+                .map(Into::into)
+                .collect()
+        };
+        match expr {
+            Expr::Nil(_) => Self::Nil,
+            Expr::Ref(name) => Self::Ref(name.to_string()),
+            Expr::Arrow {
+                tupleset,
+                function,
+                computed,
+                ..
+            } => Self::Arrow {
+                tupleset: tupleset.to_string(),
+                computed: computed.to_string(),
+                all: *function == ArrowFunction::All,
+            },
+            Expr::Union { .. } => Self::Union(operands(expr)),
+            Expr::Intersection { .. } => Self::Intersection(operands(expr)),
+            Expr::Exclusion { base, excluded, .. } => Self::Exclusion(
+                Box::new(base.as_ref().into()),
+                Box::new(excluded.as_ref().into()),
+            ),
+        }
+    }
+}
+
+/// Remove the whitespace outside of the string literals of a CEL expression.
+fn strip_whitespace(expression: &str) -> String {
+    let mut stripped = String::with_capacity(expression.len());
+    let mut quote = None;
+    let mut escaped = false;
+    for c in expression.chars() {
+        match quote {
+            Some(_) if escaped => escaped = false,
+            Some(_) if c == '\\' => escaped = true,
+            Some(open) if c == open => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c.is_whitespace() => continue,
+            None => {}
+        }
+        stripped.push(c);
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SCHEMA: &str = r#"use expiration
+/** on weekdays */
+caveat on_weekday( day int,allowed   map<list<string>> ) {
+      day < 6 &&
+      allowed["a b"].size() > 0 }
+/** a user */ definition user {}
+definition document {
+  relation parent: document relation viewer: user|user:*|group#member with on_weekday and expiration
+  // who can edit
+  /* editors */
+  relation editor: user with expiration
+  permission view = viewer + (editor + parent->view) - banned - nil
+  permission edit = editor & (viewer & parent.all(edit)) & (viewer + parent.any(view))
+}"#;
+
+    const FORMATTED: &str = r#"use expiration
+
+/** on weekdays */
+caveat on_weekday(day int, allowed map<list<string>>) {
+	day < 6 &&
+	allowed["a b"].size() > 0
+}
+
+/** a user */
+definition user {}
+
+definition document {
+	relation parent: document
+	relation viewer: user | user:* | group#member with on_weekday and expiration
+
+	// who can edit
+	/* editors */
+	relation editor: user with expiration
+	permission view = ((viewer + editor + parent->view) - banned) - nil
+	permission edit = editor & viewer & parent.all(edit) & (viewer + parent.any(view))
+}"#;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(SCHEMA).unwrap(), FORMATTED);
+        assert_eq!(format(FORMATTED).unwrap(), FORMATTED);
+        assert_eq!(
+            format("/**\n   * doc\n   */\ndefinition user {}").unwrap(),
+            "/**\n * doc\n */\ndefinition user {}"
+        );
+        assert!(format("definition user").is_err());
+
+        // Caveat bodies keep their relative indentation.
+        let caveat = "caveat c(x int, y int) {\n    x > 0 &&\n\n      y > 0   \n  }";
+        let formatted = "caveat c(x int, y int) {\n\tx > 0 &&\n\n\t  y > 0\n}";
+        assert_eq!(format(caveat).unwrap(), formatted);
+        assert_eq!(format(formatted).unwrap(), formatted);
+        assert_eq!(
+            format("caveat c(x int) { x > 0 &&\n        x < 10 }").unwrap(),
+            "caveat c(x int) {\n\tx > 0 &&\n\tx < 10\n}"
+        );
+    }
+
+    #[test]
+    fn test_format_comments() {
+        let schema = "// header
+use expiration
+
+caveat c(x int) { // always
+	x > 0 // positive
+} // c
+
+/** a user */ definition user {} // users
+
+definition document { relation viewer: user // viewers
+/* who can view */ permission view = viewer; /* or */ // nothing else
+  // more members
+  // to come
+}
+
+/* end */
+// of schema";
+        let formatted = "// header
+use expiration
+
+caveat c(x int) {
+	// always
+	x > 0 // positive
+} // c
+
+/** a user */
+definition user {} // users
+
+definition document {
+	relation viewer: user // viewers
+
+	/* who can view */
+	permission view = viewer /* or */ // nothing else
+	// more members
+	// to come
+}
+
+/* end */
+// of schema
+";
+        assert_eq!(format(schema).unwrap(), formatted);
+        assert_eq!(format(formatted).unwrap(), formatted);
+        assert_eq!(
+            format("definition document {\n}\n// users\ndefinition user {\n// none\n}").unwrap(),
+            "definition document {}\n\n// users\ndefinition user {\n\t// none\n}"
+        );
+
+        let err = |text| format(text).unwrap_err().to_string();
+        assert_eq!(
+            err("definition document {\n\tpermission view = viewer // direct\n\t\t+ editor\n}"),
+            "2:27: comments are not supported here when formatting"
+        );
+        assert_eq!(
+            err("definition document { relation viewer: /* users */ user }"),
+            "1:40: comments are not supported here when formatting"
+        );
+        assert_eq!(
+            err("definition user { // none\n}"),
+            "1:19: comments are not supported here when formatting"
+        );
+        assert_eq!(
+            err("use expiration // flag"),
+            "1:16: comments are not supported here when formatting"
+        );
+        assert_eq!(
+            err("use expiration\n// flags\nuse typechecking"),
+            "2:1: comments are not supported here when formatting"
+        );
+        // Such comments are ignored when only parsing.
+        assert!(parse("use expiration // flag").is_ok());
+    }
+
+    #[test]
+    fn test_schema_semantically_equal() {
+        let equal = |a: &str, b: &str| schema_semantically_equal(a, b).unwrap();
+        assert!(equal(SCHEMA, FORMATTED));
+
+        let schema = "definition user {}
+            definition document {
+                relation viewer: user | group#member
+                permission view = (viewer + editor) + owner & parent->view
+            }";
+        let reordered = "// documents
+            definition document {
+                permission view = parent.any(view) & (owner + editor + viewer)
+                relation viewer: group#member | user
+            }
+            definition user {}";
+        assert!(equal(schema, reordered));
+        assert!(!equal(schema, &reordered.replace("group#member", "group")));
+        assert!(!equal(schema, &reordered.replace("any", "all")));
+        assert!(!equal(
+            "definition document { permission view = a - b }",
+            "definition document { permission view = b - a }"
+        ));
+        assert!(!equal(
+            "caveat c(x int) { x == 'a b' }",
+            "caveat c(x int) { x == 'ab' }"
+        ));
+        assert!(equal(
+            "caveat c(x int) { x == 'a b' }",
+            "caveat c(x int) {\n\tx=='a b'\n}"
+        ));
+        assert!(schema_semantically_equal(schema, "definition").is_err());
+    }
+}
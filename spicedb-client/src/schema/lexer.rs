@@ -0,0 +1,171 @@
+//! Tokens of the schema language.
+
+use super::ast::Span;
+use super::ParseError;
+
+const PUNCTUATION: &[&str] = &[
+    "->", "{", "}", "(", ")", "<", ">", ":", ";", ",", "|", "+", "&", "-", "=", "#", "*", ".", "/",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TokenKind {
+    Ident,
+    Comment,
+    Punct(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Token {
+    pub(super) kind: TokenKind,
+    pub(super) span: Span,
+}
+
+pub(super) struct Lexer<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub(super) fn new(text: &'a str) -> Self {
+        Self { text, position: 0 }
+    }
+
+    pub(super) fn text(&self, span: Span) -> &'a str {
+        &self.text[span.start..span.end]
+    }
+
+    pub(super) fn error(&self, message: impl Into<String>, span: Span) -> ParseError {
+        ParseError::new(message, span, self.text)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    fn token(&mut self, kind: TokenKind, len: usize) -> Token {
+        let span = Span::new(self.position, self.position + len);
+        self.position += len;
+        Token { kind, span }
+    }
+
+    pub(super) fn next_token(&mut self) -> Result<Token, ParseError> {
+        let rest = self.rest();
+        let trimmed = rest.trim_start();
+        self.position += rest.len() - trimmed.len();
+        let rest = trimmed;
+
+        let Some(c) = rest.chars().next() else {
+            return Ok(self.token(TokenKind::Eof, 0));
+        };
+        if rest.starts_with("//") {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            return Ok(self.token(TokenKind::Comment, rest[..len].trim_end().len()));
+        }
+        if let Some(comment) = rest.strip_prefix("/*") {
+            let Some(end) = comment.find("*/") else {
+                let span = Span::new(self.position, self.text.len());
+                return Err(self.error("unterminated comment", span));
+            };
+            return Ok(self.token(TokenKind::Comment, end + 4));
+        }
+        if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            return Ok(self.token(TokenKind::Ident, len));
+        }
+        if let Some(punct) = PUNCTUATION.iter().find(|punct| rest.starts_with(**punct)) {
+            return Ok(self.token(TokenKind::Punct(punct), punct.len()));
+        }
+
+        let span = Span::new(self.position, self.position + c.len_utf8());
+        Err(self.error(format!("unexpected character `{c}`"), span))
+    }
+
+    /// Consume the CEL expression of a caveat up to its closing brace, which
+    /// is also consumed, returning the span of the expression.
+    pub(super) fn cel_expression(&mut self) -> Result<Span, ParseError> {
+        let start = self.position;
+        let mut depth = 0;
+        let mut chars = self.rest().char_indices();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => {
+                    self.position = start + offset + 1;
+                    return Ok(Span::new(start, start + offset));
+                }
+                '}' => depth -= 1,
+                '"' | '\'' => {
+                    // Skip string literals, which may contain braces.
+                    let mut escaped = false;
+                    for (_, next) in chars.by_ref() {
+                        match next {
+                            '\\' if !escaped => escaped = true,
+                            next if next == c && !escaped => break,
+                            _ => escaped = false,
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        let span = Span::new(start, self.text.len());
+        Err(self.error("expected `}` after the caveat expression", span))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tokens(text: &str) -> Vec<(TokenKind, &str)> {
+        let mut lexer = Lexer::new(text);
+        let mut tokens = vec![];
+        loop {
+            let token = lexer.next_token().unwrap();
+            if token.kind == TokenKind::Eof {
+                return tokens;
+            }
+            tokens.push((token.kind, lexer.text(token.span)));
+        }
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            tokens("permission view = parent->view - tenant/banned // comment\n/** doc */"),
+            [
+                (TokenKind::Ident, "permission"),
+                (TokenKind::Ident, "view"),
+                (TokenKind::Punct("="), "="),
+                (TokenKind::Ident, "parent"),
+                (TokenKind::Punct("->"), "->"),
+                (TokenKind::Ident, "view"),
+                (TokenKind::Punct("-"), "-"),
+                (TokenKind::Ident, "tenant"),
+                (TokenKind::Punct("/"), "/"),
+                (TokenKind::Ident, "banned"),
+                (TokenKind::Comment, "// comment"),
+                (TokenKind::Comment, "/** doc */"),
+            ]
+        );
+
+        let mut lexer = Lexer::new("definition\n  @");
+        lexer.next_token().unwrap();
+        let err = lexer.next_token().unwrap_err();
+        assert_eq!(err.to_string(), "2:3: unexpected character `@`");
+    }
+
+    #[test]
+    fn test_cel_expression() {
+        let mut lexer = Lexer::new(r#" {"}" in m} && x == '}' } definition"#);
+        let span = lexer.cel_expression().unwrap();
+        assert_eq!(lexer.text(span), r#" {"}" in m} && x == '}' "#);
+        let token = lexer.next_token().unwrap();
+        assert_eq!(lexer.text(token.span), "definition");
+
+        assert!(Lexer::new("x == 1").cel_expression().is_err());
+    }
+}
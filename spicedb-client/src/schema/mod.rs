@@ -0,0 +1,15 @@
+//! Typed models of SpiceDB schemas.
+
+pub mod ast;
+mod check;
+mod diff;
+mod format;
+mod lexer;
+mod parser;
+mod reflect;
+
+pub use check::*;
+pub use diff::*;
+pub use format::*;
+pub use parser::*;
+pub use reflect::*;
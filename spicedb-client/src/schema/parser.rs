@@ -0,0 +1,693 @@
+use thiserror::Error;
+
+use super::ast::{
+    ArrowFunction, Caveat, CaveatParameter, Definition, Expr, Ident, Item, Member, Permission,
+    Relation, Schema, Span, SubjectType,
+};
+use super::lexer::{Lexer, Token, TokenKind};
+use super::{CaveatParameterType, SubjectTypeKind};
+
+/// Error returned by [`parse`] at the first invalid token of the schema, and
+/// by [`format`](super::format) at a comment it cannot keep.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("{line}:{column}: {message}")]
+pub struct ParseError {
+    pub message: String,
+    pub span: Span,
+    /// 1-based line of the start of the span.
+    pub line: usize,
+    /// 1-based column of the start of the span, in characters.
+    pub column: usize,
+}
+
+impl ParseError {
+    pub(super) fn new(message: impl Into<String>, span: Span, text: &str) -> Self {
+        let (line, column) = span.line_column(text);
+        Self {
+            message: message.into(),
+            span,
+            line,
+            column,
+        }
+    }
+}
+
+/// Parse a schema written in the SpiceDB schema language.
+///
+/// Only the syntax is checked: references to undefined definitions,
+/// relations or caveats are not errors.
+///
+/// ```rust
+/// # use spicedb_client::schema::parse;
+/// #
+/// let err = parse("definition document {\n    relation viewer user\n}").unwrap_err();
+/// assert_eq!(err.to_string(), "2:21: expected `:`, found `user`");
+/// ```
+pub fn parse(text: &str) -> Result<Schema, ParseError> {
+    Parser::new(text).schema()
+}
+
+/// Parse a schema, failing at the first comment that is not kept in the
+/// syntax tree, such as a comment inside a permission expression.
+pub(super) fn parse_with_comments(text: &str) -> Result<Schema, ParseError> {
+    let mut parser = Parser::new(text);
+    let schema = parser.schema()?;
+    match parser.dropped_comments.first() {
+        Some(comment) => Err(parser
+            .lexer
+            .error("comments are not supported here when formatting", *comment)),
+        None => Ok(schema),
+    }
+}
+
+struct Parser<'a> {
+    lexer: Lexer<'a>,
+    peeked: Option<Token>,
+    /// Comments before the peeked token.
+    comments: Vec<Span>,
+    /// End of the last consumed token.
+    previous_end: Option<usize>,
+    /// Comments that are not kept in the syntax tree.
+    dropped_comments: Vec<Span>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            lexer: Lexer::new(text),
+            peeked: None,
+            comments: Vec::new(),
+            previous_end: None,
+            dropped_comments: Vec::new(),
+        }
+    }
+
+    fn peek(&mut self) -> Result<Token, ParseError> {
+        if let Some(token) = self.peeked {
+            return Ok(token);
+        }
+        loop {
+            let token = self.lexer.next_token()?;
+            if token.kind != TokenKind::Comment {
+                self.peeked = Some(token);
+                return Ok(token);
+            }
+            self.comments.push(token.span);
+        }
+    }
+
+    fn bump(&mut self) -> Result<Token, ParseError> {
+        let token = self.peek()?;
+        self.peeked = None;
+        self.dropped_comments.append(&mut self.comments);
+        self.previous_end = Some(token.span.end);
+        Ok(token)
+    }
+
+    /// Whether `comment` starts on the line of the last consumed token, and
+    /// so belongs to it rather than to the next token.
+    fn is_trailing(&self, comment: Span) -> bool {
+        self.previous_end.is_some_and(|end| {
+            !self
+                .lexer
+                .text(Span::new(end, comment.start))
+                .contains('\n')
+        })
+    }
+
+    /// The doc comment of the next token: the comments before it that start
+    /// on their own line.
+    fn take_comment(&mut self) -> Result<String, ParseError> {
+        self.peek()?;
+        let leading = self
+            .comments
+            .iter()
+            .position(|comment| !self.is_trailing(*comment))
+            .unwrap_or(self.comments.len());
+        let comments: Vec<_> = self
+            .comments
+            .drain(leading..)
+            .map(|span| self.lexer.text(span))
+            .collect();
+        Ok(comments.join("\n"))
+    }
+
+    /// The comments after the last consumed token, on the same line.
+    fn take_trailing_comment(&mut self) -> Result<String, ParseError> {
+        self.peek()?;
+        let trailing = self
+            .comments
+            .iter()
+            .position(|comment| !self.is_trailing(*comment))
+            .unwrap_or(self.comments.len());
+        let comments: Vec<_> = self
+            .comments
+            .drain(..trailing)
+            .map(|span| self.lexer.text(span))
+            .collect();
+        Ok(comments.join(" "))
+    }
+
+    fn eat(&mut self, punct: &'static str) -> Result<Option<Token>, ParseError> {
+        if self.peek()?.kind == TokenKind::Punct(punct) {
+            return self.bump().map(Some);
+        }
+        Ok(None)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> Result<bool, ParseError> {
+        let token = self.peek()?;
+        if token.kind == TokenKind::Ident && self.lexer.text(token.span) == keyword {
+            self.bump()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    fn expect(&mut self, punct: &'static str) -> Result<Token, ParseError> {
+        match self.eat(punct)? {
+            Some(token) => Ok(token),
+            None => Err(self.unexpected(&format!("`{punct}`"))?),
+        }
+    }
+
+    /// Error for an unexpected next token, where `expected` was expected.
+    fn unexpected(&mut self, expected: &str) -> Result<ParseError, ParseError> {
+        let token = self.peek()?;
+        let found = match token.kind {
+            TokenKind::Eof => "end of schema".to_string(),
+            _ => format!("`{}`", self.lexer.text(token.span)),
+        };
+        Ok(self
+            .lexer
+            .error(format!("expected {expected}, found {found}"), token.span))
+    }
+
+    fn ident(&mut self, expected: &str) -> Result<Ident, ParseError> {
+        let token = self.peek()?;
+        if token.kind != TokenKind::Ident {
+            return Err(self.unexpected(expected)?);
+        }
+        self.bump()?;
+        Ok(Ident {
+            name: self.lexer.text(token.span).to_string(),
+            span: token.span,
+        })
+    }
+
+    /// A name with optional prefixes, such as `tenant/document`.
+    fn path(&mut self, expected: &str) -> Result<Ident, ParseError> {
+        let mut path = self.ident(expected)?;
+        while self.eat("/")?.is_some() {
+            let segment = self.ident(expected)?;
+            path.name = format!("{}/{}", path.name, segment.name);
+            path.span = path.span.to(segment.span);
+        }
+        Ok(path)
+    }
+
+    fn schema(&mut self) -> Result<Schema, ParseError> {
+        let mut schema = Schema::default();
+        // Comments before the leading flags are the header of the schema.
+        let token = self.peek()?;
+        if token.kind == TokenKind::Ident && self.lexer.text(token.span) == "use" {
+            schema.comment = self.take_comment()?;
+        }
+        loop {
+            // Other comments around flags are not kept.
+            if self.eat_keyword("use")? {
+                schema.items.push(Item::Use(self.ident("a feature name")?));
+                self.eat(";")?;
+                continue;
+            }
+            let comment = self.take_comment()?;
+            let start = self.peek()?.span;
+            let item = if self.eat_keyword("definition")? {
+                Item::Definition(self.definition(comment, start)?)
+            } else if self.eat_keyword("caveat")? {
+                Item::Caveat(self.caveat(comment, start)?)
+            } else if self.peek()?.kind == TokenKind::Eof {
+                schema.end_comment = comment;
+                self.dropped_comments.append(&mut self.comments);
+                return Ok(schema);
+            } else {
+                return Err(self.unexpected("`definition`, `caveat` or `use`")?);
+            };
+            schema.items.push(item);
+        }
+    }
+
+    fn definition(&mut self, comment: String, start: Span) -> Result<Definition, ParseError> {
+        let name = self.path("a definition name")?;
+        self.expect("{")?;
+        let mut members = Vec::new();
+        loop {
+            let member_comment = self.take_comment()?;
+            if let Some(close) = self.eat("}")? {
+                self.eat(";")?;
+                return Ok(Definition {
+                    comment,
+                    name,
+                    members,
+                    end_comment: member_comment,
+                    trailing_comment: self.take_trailing_comment()?,
+                    span: start.to(close.span),
+                });
+            }
+            let start = self.peek()?.span;
+            let member = if self.eat_keyword("relation")? {
+                Member::Relation(self.relation(member_comment, start)?)
+            } else if self.eat_keyword("permission")? {
+                Member::Permission(self.permission(member_comment, start)?)
+            } else {
+                return Err(self.unexpected("`relation`, `permission` or `}`")?);
+            };
+            members.push(member);
+        }
+    }
+
+    fn relation(&mut self, comment: String, start: Span) -> Result<Relation, ParseError> {
+        let name = self.ident("a relation name")?;
+        self.expect(":")?;
+        let mut subject_types = vec![self.subject_type()?];
+        while self.eat("|")?.is_some() {
+            subject_types.push(self.subject_type()?);
+        }
+        let end = subject_types[subject_types.len() - 1].span;
+        self.eat(";")?;
+        Ok(Relation {
+            comment,
+            name,
+            subject_types,
+            trailing_comment: self.take_trailing_comment()?,
+            span: start.to(end),
+        })
+    }
+
+    fn subject_type(&mut self) -> Result<SubjectType, ParseError> {
+        let definition = self.path("a subject type")?;
+        let mut end = definition.span;
+        let mut kind = SubjectTypeKind::Object;
+        if self.eat("#")?.is_some() {
+            let relation = self.ident("a relation name")?;
+            end = relation.span;
+            kind = SubjectTypeKind::Relation(relation.name);
+        } else if self.eat(":")?.is_some() {
+            end = self.expect("*")?.span;
+            kind = SubjectTypeKind::Wildcard;
+        }
+
+        let mut caveat = None;
+        let mut expiration = false;
+        if self.eat_keyword("with")? {
+            loop {
+                let name = self.path("a caveat name or `expiration`")?;
+                end = name.span;
+                if name.as_str() == "expiration" {
+                    expiration = true;
+                } else if caveat.is_some() {
+                    let message = "a subject type can have at most one caveat";
+                    return Err(self.lexer.error(message, name.span));
+                } else {
+                    caveat = Some(name);
+                }
+                if !self.eat_keyword("and")? {
+                    break;
+                }
+            }
+        }
+
+        Ok(SubjectType {
+            span: definition.span.to(end),
+            definition,
+            kind,
+            caveat,
+            expiration,
+        })
+    }
+
+    fn permission(&mut self, comment: String, start: Span) -> Result<Permission, ParseError> {
+        let name = self.ident("a permission name")?;
+        self.expect("=")?;
+        let expression = self.exclusion()?;
+        self.eat(";")?;
+        Ok(Permission {
+            comment,
+            name,
+            span: start.to(expression.span()),
+            expression,
+            trailing_comment: self.take_trailing_comment()?,
+        })
+    }
+
+    /// As in SpiceDB, exclusion has the lowest precedence and union the
+    /// highest.
+    fn exclusion(&mut self) -> Result<Expr, ParseError> {
+        let mut expr = self.intersection()?;
+        while self.eat("-")?.is_some() {
+            let excluded = self.intersection()?;
+            expr = Expr::Exclusion {
+                span: expr.span().to(excluded.span()),
+                base: Box::new(expr),
+                excluded: Box::new(excluded),
+            };
+        }
+        Ok(expr)
+    }
+
+    fn intersection(&mut self) -> Result<Expr, ParseError> {
+        let mut operands = vec![self.union()?];
+        while self.eat("&")?.is_some() {
+            operands.push(self.union()?);
+        }
+        if operands.len() == 1 {
+            return Ok(operands.remove(0));
+        }
+        let span = operands[0].span().to(operands[operands.len() - 1].span());
+        Ok(Expr::Intersection { operands, span })
+    }
+
+    fn union(&mut self) -> Result<Expr, ParseError> {
+        let mut operands = vec![self.operand()?];
+        while self.eat("+")?.is_some() {
+            operands.push(self.operand()?);
+        }
+        if operands.len() == 1 {
+            return Ok(operands.remove(0));
+        }
+        let span = operands[0].span().to(operands[operands.len() - 1].span());
+        Ok(Expr::Union { operands, span })
+    }
+
+    fn operand(&mut self) -> Result<Expr, ParseError> {
+        if self.eat("(")?.is_some() {
+            let expr = self.exclusion()?;
+            self.expect(")")?;
+            return Ok(expr);
+        }
+
+        let name = self.ident("a relation or permission")?;
+        if name.as_str() == "nil" {
+            return Ok(Expr::Nil(name.span));
+        }
+        if self.eat("->")?.is_some() {
+            let computed = self.ident("a relation or permission")?;
+            return Ok(Expr::Arrow {
+                span: name.span.to(computed.span),
+                tupleset: name,
+                function: ArrowFunction::Arrow,
+                computed,
+            });
+        }
+        if self.eat(".")?.is_some() {
+            let function = self.ident("`any` or `all`")?;
+            let function = match function.as_str() {
+                "any" => ArrowFunction::Any,
+                "all" => ArrowFunction::All,
+                other => {
+                    let message =
+                        format!("unknown arrow function `{other}`, expected `any` or `all`");
+                    return Err(self.lexer.error(message, function.span));
+                }
+            };
+            self.expect("(")?;
+            let computed = self.ident("a relation or permission")?;
+            let close = self.expect(")")?;
+            return Ok(Expr::Arrow {
+                span: name.span.to(close.span),
+                tupleset: name,
+                function,
+                computed,
+            });
+        }
+        Ok(Expr::Ref(name))
+    }
+
+    fn caveat(&mut self, comment: String, start: Span) -> Result<Caveat, ParseError> {
+        let name = self.path("a caveat name")?;
+        self.expect("(")?;
+        let mut parameters = Vec::new();
+        while self.eat(")")?.is_none() {
+            if !parameters.is_empty() {
+                self.expect(",")?;
+            }
+            let name = self.ident("a parameter name")?;
+            let type_span = self.type_reference()?;
+            let type_name: String = self.lexer.text(type_span).split_whitespace().collect();
+            parameters.push(CaveatParameter {
+                span: name.span.to(type_span),
+                name,
+                parameter_type: CaveatParameterType::parse(&type_name),
+                type_span,
+            });
+        }
+
+        self.expect("{")?;
+        let expression_span = self.lexer.cel_expression()?;
+        let end = Span::new(expression_span.end, expression_span.end + 1);
+        self.previous_end = Some(end.end);
+        self.eat(";")?;
+        Ok(Caveat {
+            comment,
+            name,
+            parameters,
+            expression: dedent(self.lexer.text(expression_span)),
+            expression_span,
+            trailing_comment: self.take_trailing_comment()?,
+            span: start.to(end),
+        })
+    }
+
+    /// A caveat parameter type, such as `map<list<string>>`.
+    fn type_reference(&mut self) -> Result<Span, ParseError> {
+        let name = self.ident("a parameter type")?;
+        if self.eat("<")?.is_none() {
+            return Ok(name.span);
+        }
+        self.type_reference()?;
+        while self.eat(",")?.is_some() {
+            self.type_reference()?;
+        }
+        let close = self.expect(">")?;
+        Ok(name.span.to(close.span))
+    }
+}
+
+/// Remove the blank lines around `text`, the trailing whitespace of its
+/// lines and their common indentation. A first line that follows the opening
+/// brace is not indented, and is only trimmed.
+fn dedent(text: &str) -> String {
+    let text = text.trim_end();
+    let content = text.trim_start();
+    let (first, rest) = match text[..text.len() - content.len()].rfind('\n') {
+        Some(newline) => (None, &text[newline + 1..]),
+        None => match content.split_once('\n') {
+            Some((first, rest)) => (Some(first.trim_end()), rest),
+            None => (Some(content), ""),
+        },
+    };
+
+    let is_blank = |line: &str| line.trim().is_empty();
+    let indent = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let common = rest
+        .lines()
+        .filter(|line| !is_blank(line))
+        .map(indent)
+        .min()
+        .unwrap_or(0);
+    let lines = rest.lines().map(|line| match is_blank(line) {
+        true => "",
+        false => line[common..].trim_end(),
+    });
+    first
+        .into_iter()
+        .chain(lines)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SCHEMA: &str = r#"use expiration
+
+/** on weekdays */
+caveat tenant/on_weekday(day int, allowed map<list<string>>) {
+    day < 6 && allowed["}"].size() > 0
+}
+
+definition tenant/user {}
+
+// A document.
+definition tenant/document {
+    relation parent: tenant/document
+    /** who can view */
+    relation viewer: tenant/user | tenant/user:* | group#member with tenant/on_weekday and expiration;
+    permission view = viewer + parent->view - banned & viewer
+    permission view_all = (viewer + parent.all(view)) & nil
+}
+"#;
+
+    fn name(expr: &Expr) -> &str {
+        match expr {
+            Expr::Ref(name) => name.as_str(),
+            _ => panic!("not a reference: {expr:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let schema = parse(SCHEMA).unwrap();
+        assert_eq!(schema.items.len(), 4);
+        assert!(matches!(&schema.items[0], Item::Use(flag) if flag.as_str() == "expiration"));
+
+        let caveat = schema.caveat("tenant/on_weekday").unwrap();
+        assert_eq!(caveat.comment, "/** on weekdays */");
+        assert_eq!(caveat.expression, r#"day < 6 && allowed["}"].size() > 0"#);
+        assert_eq!(
+            caveat.parameter("allowed").unwrap().parameter_type,
+            CaveatParameterType::Map(Box::new(CaveatParameterType::List(Box::new(
+                CaveatParameterType::String
+            ))))
+        );
+        assert_eq!(&SCHEMA[caveat.span.start..caveat.span.start + 6], "caveat");
+        assert!(SCHEMA[..caveat.span.end].ends_with('}'));
+
+        let document = schema.definition("tenant/document").unwrap();
+        assert_eq!(document.comment, "// A document.");
+        assert_eq!(document.members.len(), 4);
+
+        let viewer = document.relation("viewer").unwrap();
+        assert_eq!(viewer.comment, "/** who can view */");
+        let subject_types: Vec<_> = viewer
+            .subject_types
+            .iter()
+            .map(|subject_type| &SCHEMA[subject_type.span.start..subject_type.span.end])
+            .collect();
+        assert_eq!(
+            subject_types,
+            [
+                "tenant/user",
+                "tenant/user:*",
+                "group#member with tenant/on_weekday and expiration"
+            ]
+        );
+        let group = &viewer.subject_types[2];
+        assert_eq!(group.kind, SubjectTypeKind::Relation("member".to_string()));
+        assert_eq!(group.caveat.as_ref().unwrap().as_str(), "tenant/on_weekday");
+        assert!(group.expiration);
+        assert_eq!(viewer.subject_types[1].kind, SubjectTypeKind::Wildcard);
+
+        // Exclusion has the lowest precedence and union the highest.
+        let view = document.permission("view").unwrap();
+        let Expr::Exclusion { base, excluded, .. } = &view.expression else {
+            panic!("not an exclusion: {:?}", view.expression);
+        };
+        let Expr::Union { operands, .. } = base.as_ref() else {
+            panic!("not a union: {base:?}");
+        };
+        assert_eq!(name(&operands[0]), "viewer");
+        assert!(matches!(
+            &operands[1],
+            Expr::Arrow { tupleset, function: ArrowFunction::Arrow, computed, .. }
+                if tupleset.as_str() == "parent" && computed.as_str() == "view"
+        ));
+        let Expr::Intersection { operands, span } = excluded.as_ref() else {
+            panic!("not an intersection: {excluded:?}");
+        };
+        assert_eq!(&SCHEMA[span.start..span.end], "banned & viewer");
+        assert_eq!(operands.len(), 2);
+
+        let view_all = document.permission("view_all").unwrap();
+        let Expr::Intersection { operands, .. } = &view_all.expression else {
+            panic!("not an intersection: {:?}", view_all.expression);
+        };
+        assert!(matches!(&operands[1], Expr::Nil(_)));
+        let Expr::Union { operands, .. } = &operands[0] else {
+            panic!("not a union: {:?}", operands[0]);
+        };
+        assert!(matches!(
+            &operands[1],
+            Expr::Arrow { function: ArrowFunction::All, span, .. }
+                if &SCHEMA[span.start..span.end] == "parent.all(view)"
+        ));
+    }
+
+    #[test]
+    fn test_trailing_comments() {
+        let schema = parse(
+            "definition user {} // users
+            definition document {
+                relation owner: user // the creator
+                /* who can edit */ relation editor: user /* or owner */ // editors
+                relation viewer: user; // viewers
+
+                // direct viewers
+                permission view = viewer
+            }",
+        )
+        .unwrap();
+        assert_eq!(
+            schema.definition("user").unwrap().trailing_comment,
+            "// users"
+        );
+        let document = schema.definition("document").unwrap();
+        assert_eq!(document.comment, "");
+        assert_eq!(document.member("owner").unwrap().comment(), "");
+        assert_eq!(
+            document.member("owner").unwrap().trailing_comment(),
+            "// the creator"
+        );
+        assert_eq!(
+            document.member("editor").unwrap().comment(),
+            "/* who can edit */"
+        );
+        assert_eq!(
+            document.member("editor").unwrap().trailing_comment(),
+            "/* or owner */ // editors"
+        );
+        assert_eq!(document.member("viewer").unwrap().comment(), "");
+        assert_eq!(
+            document.member("viewer").unwrap().trailing_comment(),
+            "// viewers"
+        );
+        assert_eq!(
+            document.member("view").unwrap().comment(),
+            "// direct viewers"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = |text| parse(text).unwrap_err().to_string();
+        assert_eq!(
+            err("definition user {}\ndefinition document {\n  relation viewer: \n}"),
+            "4:1: expected a subject type, found `}`"
+        );
+        assert_eq!(
+            err("definition document { permission view = viewer + }"),
+            "1:50: expected a relation or permission, found `}`"
+        );
+        assert_eq!(
+            err("definition document { permission view = parent.some(view) }"),
+            "1:48: unknown arrow function `some`, expected `any` or `all`"
+        );
+        assert_eq!(
+            err("definition document { relation viewer: user with a and b }"),
+            "1:56: a subject type can have at most one caveat"
+        );
+        assert_eq!(
+            err("definition document {"),
+            "1:22: expected `relation`, `permission` or `}`, found end of schema"
+        );
+        assert_eq!(
+            err("relation viewer: user"),
+            "1:1: expected `definition`, `caveat` or `use`, found `relation`"
+        );
+        assert_eq!(
+            err("caveat c(x int) { x > 1"),
+            "1:18: expected `}` after the caveat expression"
+        );
+    }
+}
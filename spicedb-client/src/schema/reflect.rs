@@ -0,0 +1,403 @@
+use spicedb_grpc::authzed::api::v1::{
+    exp_type_reference::Typeref, ExpCaveat, ExpCaveatParameter, ExpDefinition, ExpPermission,
+    ExpRelation, ExpTypeReference, ExperimentalReflectSchemaResponse, ZedToken,
+};
+
+/// Schema reflected from SpiceDB with
+/// [`SpicedbClient::reflect_schema`](crate::SpicedbClient::reflect_schema).
+///
+/// ```rust
+/// # use spicedb_client::schema::Schema;
+/// #
+/// # fn viewer_types(schema: &Schema) -> Option<()> {
+/// let viewer = schema.definition("document")?.relation("viewer")?;
+/// for subject_type in &viewer.subject_types {
+///     println!("{subject_type}");
+/// }
+/// # Some(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Schema {
+    pub definitions: Vec<Definition>,
+    pub caveats: Vec<Caveat>,
+    /// The ZedToken at which the schema was read.
+    pub read_at: Option<ZedToken>,
+}
+
+impl Schema {
+    pub fn definition(&self, name: &str) -> Option<&Definition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.name == name)
+    }
+
+    pub fn caveat(&self, name: &str) -> Option<&Caveat> {
+        self.caveats.iter().find(|caveat| caveat.name == name)
+    }
+}
+
+impl From<ExperimentalReflectSchemaResponse> for Schema {
+    fn from(response: ExperimentalReflectSchemaResponse) -> Self {
+        Self {
+            definitions: response.definitions.into_iter().map(Into::into).collect(),
+            caveats: response.caveats.into_iter().map(Into::into).collect(),
+            read_at: response.read_at,
+        }
+    }
+}
+
+/// An object definition, such as `definition document { ... }`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Definition {
+    pub name: String,
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub relations: Vec<Relation>,
+    pub permissions: Vec<Permission>,
+}
+
+impl Definition {
+    pub fn relation(&self, name: &str) -> Option<&Relation> {
+        self.relations.iter().find(|relation| relation.name == name)
+    }
+
+    pub fn permission(&self, name: &str) -> Option<&Permission> {
+        self.permissions
+            .iter()
+            .find(|permission| permission.name == name)
+    }
+}
+
+impl From<ExpDefinition> for Definition {
+    fn from(definition: ExpDefinition) -> Self {
+        Self {
+            name: definition.name,
+            comment: definition.comment,
+            relations: definition.relations.into_iter().map(Into::into).collect(),
+            permissions: definition.permissions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A relation, such as `relation viewer: user | group#member`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Relation {
+    pub name: String,
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    /// Name of the definition the relation belongs to.
+    pub definition: String,
+    /// Allowed subject types.
+    pub subject_types: Vec<SubjectType>,
+}
+
+impl Relation {
+    /// Whether subjects of `definition` are allowed, directly, through a
+    /// relation or as a wildcard.
+    pub fn allows(&self, definition: &str) -> bool {
+        self.subject_types
+            .iter()
+            .any(|subject_type| subject_type.definition == definition)
+    }
+}
+
+impl From<ExpRelation> for Relation {
+    fn from(relation: ExpRelation) -> Self {
+        Self {
+            name: relation.name,
+            comment: relation.comment,
+            definition: relation.parent_definition_name,
+            subject_types: relation.subject_types.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// An allowed subject type of a relation, such as `user`, `group#member`,
+/// `user:*` or `user with ip_allowlist`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SubjectType {
+    /// Name of the subject's definition.
+    pub definition: String,
+    pub kind: SubjectTypeKind,
+    /// Caveat that must be satisfied for the relationship, if any.
+    pub caveat: Option<String>,
+}
+
+impl From<ExpTypeReference> for SubjectType {
+    fn from(reference: ExpTypeReference) -> Self {
+        let kind = match reference.typeref {
+            Some(Typeref::OptionalRelationName(relation)) if !relation.is_empty() => {
+                SubjectTypeKind::Relation(relation)
+            }
+            Some(Typeref::IsPublicWildcard(true)) => SubjectTypeKind::Wildcard,
+            _ => SubjectTypeKind::Object,
+        };
+        Self {
+            definition: reference.subject_definition_name,
+            kind,
+            caveat: Some(reference.optional_caveat_name).filter(|caveat| !caveat.is_empty()),
+        }
+    }
+}
+
+impl std::fmt::Display for SubjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.definition)?;
+        match &self.kind {
+            SubjectTypeKind::Object => {}
+            SubjectTypeKind::Relation(relation) => write!(f, "#{relation}")?,
+            SubjectTypeKind::Wildcard => f.write_str(":*")?,
+        }
+        if let Some(caveat) = &self.caveat {
+            write!(f, " with {caveat}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How a subject type refers to subjects.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SubjectTypeKind {
+    /// Objects of the definition, such as `user`.
+    #[default]
+    Object,
+    /// Subjects of a relation of the definition, such as `group#member`.
+    Relation(String),
+    /// All objects of the definition, such as `user:*`.
+    Wildcard,
+}
+
+/// A permission, such as `permission view = viewer + editor`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Permission {
+    pub name: String,
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    /// Name of the definition the permission belongs to.
+    pub definition: String,
+}
+
+impl From<ExpPermission> for Permission {
+    fn from(permission: ExpPermission) -> Self {
+        Self {
+            name: permission.name,
+            comment: permission.comment,
+            definition: permission.parent_definition_name,
+        }
+    }
+}
+
+/// A caveat, such as `caveat ip_allowlist(user_ip ipaddress, cidrs list<string>) { ... }`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Caveat {
+    pub name: String,
+    /// Doc comment, including the comment delimiters.
+    pub comment: String,
+    pub parameters: Vec<CaveatParameter>,
+    /// The CEL expression of the caveat.
+    pub expression: String,
+}
+
+impl Caveat {
+    pub fn parameter(&self, name: &str) -> Option<&CaveatParameter> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+    }
+}
+
+impl From<ExpCaveat> for Caveat {
+    fn from(caveat: ExpCaveat) -> Self {
+        Self {
+            name: caveat.name,
+            comment: caveat.comment,
+            parameters: caveat.parameters.into_iter().map(Into::into).collect(),
+            expression: caveat.expression,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaveatParameter {
+    pub name: String,
+    pub parameter_type: CaveatParameterType,
+}
+
+impl From<ExpCaveatParameter> for CaveatParameter {
+    fn from(parameter: ExpCaveatParameter) -> Self {
+        Self {
+            name: parameter.name,
+            parameter_type: CaveatParameterType::parse(&parameter.r#type),
+        }
+    }
+}
+
+/// Type of a caveat parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CaveatParameterType {
+    Any,
+    Bool,
+    Int,
+    Uint,
+    Double,
+    String,
+    Bytes,
+    Duration,
+    Timestamp,
+    IpAddress,
+    List(Box<CaveatParameterType>),
+    /// A map with string keys.
+    Map(Box<CaveatParameterType>),
+    /// A type not known to this client.
+    Unknown(String),
+}
+
+impl CaveatParameterType {
+    /// Parse a type as written in the schema, such as `list<string>`.
+    pub fn parse(type_name: &str) -> Self {
+        let type_name = type_name.trim();
+        let generic = |prefix: &str| {
+            type_name
+                .strip_prefix(prefix)?
+                .trim_start()
+                .strip_prefix('<')?
+                .strip_suffix('>')
+                .map(|inner| Box::new(Self::parse(inner)))
+        };
+
+        match type_name {
+            "any" => Self::Any,
+            "bool" => Self::Bool,
+            "int" => Self::Int,
+            "uint" => Self::Uint,
+            "double" => Self::Double,
+            "string" => Self::String,
+            "bytes" => Self::Bytes,
+            "duration" => Self::Duration,
+            "timestamp" => Self::Timestamp,
+            "ipaddress" => Self::IpAddress,
+            _ => {
+                if let Some(inner) = generic("list") {
+                    Self::List(inner)
+                } else if let Some(inner) = generic("map") {
+                    Self::Map(inner)
+                } else {
+                    Self::Unknown(type_name.to_string())
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CaveatParameterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => f.write_str("any"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int => f.write_str("int"),
+            Self::Uint => f.write_str("uint"),
+            Self::Double => f.write_str("double"),
+            Self::String => f.write_str("string"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::Duration => f.write_str("duration"),
+            Self::Timestamp => f.write_str("timestamp"),
+            Self::IpAddress => f.write_str("ipaddress"),
+            Self::List(inner) => write!(f, "list<{inner}>"),
+            Self::Map(inner) => write!(f, "map<{inner}>"),
+            Self::Unknown(type_name) => f.write_str(type_name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_response() {
+        let response = ExperimentalReflectSchemaResponse {
+            definitions: vec![ExpDefinition {
+                name: "document".to_string(),
+                relations: vec![ExpRelation {
+                    name: "viewer".to_string(),
+                    parent_definition_name: "document".to_string(),
+                    subject_types: vec![
+                        ExpTypeReference {
+                            subject_definition_name: "user".to_string(),
+                            optional_caveat_name: "ip_allowlist".to_string(),
+                            typeref: Some(Typeref::IsTerminalSubject(true)),
+                        },
+                        ExpTypeReference {
+                            subject_definition_name: "group".to_string(),
+                            typeref: Some(Typeref::OptionalRelationName("member".to_string())),
+                            ..Default::default()
+                        },
+                        ExpTypeReference {
+                            subject_definition_name: "user".to_string(),
+                            typeref: Some(Typeref::IsPublicWildcard(true)),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                }],
+                permissions: vec![ExpPermission {
+                    name: "view".to_string(),
+                    parent_definition_name: "document".to_string(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            caveats: vec![ExpCaveat {
+                name: "ip_allowlist".to_string(),
+                parameters: vec![ExpCaveatParameter {
+                    name: "cidrs".to_string(),
+                    r#type: "list<string>".to_string(),
+                    parent_caveat_name: "ip_allowlist".to_string(),
+                }],
+                expression: "user_ip.in_cidr(cidrs[0])".to_string(),
+                ..Default::default()
+            }],
+            read_at: None,
+        };
+        let schema = Schema::from(response);
+
+        let document = schema.definition("document").unwrap();
+        let viewer = document.relation("viewer").unwrap();
+        let subject_types: Vec<_> = viewer
+            .subject_types
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            subject_types,
+            ["user with ip_allowlist", "group#member", "user:*"]
+        );
+        assert!(viewer.allows("group"));
+        assert!(!viewer.allows("folder"));
+        assert_eq!(document.permission("view").unwrap().definition, "document");
+        assert!(document.relation("view").is_none());
+        assert!(schema.definition("folder").is_none());
+
+        let cidrs = schema
+            .caveat("ip_allowlist")
+            .unwrap()
+            .parameter("cidrs")
+            .unwrap();
+        assert_eq!(
+            cidrs.parameter_type,
+            CaveatParameterType::List(Box::new(CaveatParameterType::String))
+        );
+    }
+
+    #[test]
+    fn test_caveat_parameter_type() {
+        for type_name in ["int", "list<ipaddress>", "map<list<uint>>", "custom"] {
+            assert_eq!(CaveatParameterType::parse(type_name).to_string(), type_name);
+        }
+        assert_eq!(
+            CaveatParameterType::parse("custom"),
+            CaveatParameterType::Unknown("custom".to_string())
+        );
+    }
+}
@@ -0,0 +1,685 @@
+//! In-memory fake SpiceDB server for hermetic tests.
+//!
+//! [`FakeSpicedb`] implements the schema service, the relationship and
+//! permission methods of the permissions service and the watch service, with
+//! the relationships stored in memory. It understands schemas with relations,
+//! unions, intersections, exclusions and arrows.
+//!
+//! Unlike SpiceDB, every request is fully consistent, caveats and expiration
+//! are parsed but ignored, and `CheckBulkPermissions` and
+//! `ExpandPermissionTree` are not implemented. Ignoring caveats means a
+//! caveated relationship always grants access, as if its caveat were true.
+//!
+//! `LookupSubjects` only returns subjects that appear in some relationship.
+//! When access is granted through a wildcard, it returns `*` together with
+//! every such subject ID of the subject type, where SpiceDB returns `*` and
+//! only the subjects granted access directly.
+//!
+//! ```rust
+//! # use spicedb_client::testing::FakeSpicedb;
+//! #
+//! # async fn test() {
+//! let spicedb = FakeSpicedb::new();
+//! let client = spicedb.client();
+//! client
+//!     .write_schema("definition user {} definition document { relation viewer: user }")
+//!     .await
+//!     .unwrap();
+//! # }
+//! ```
+
+mod schema;
+mod store;
+
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use http::Uri;
+use spicedb_grpc::authzed::api::v1::{
+    check_permission_response::Permissionship,
+    permissions_service_server::{PermissionsService, PermissionsServiceServer},
+    schema_service_server::{SchemaService, SchemaServiceServer},
+    watch_service_server::{WatchService, WatchServiceServer},
+    CheckBulkPermissionsRequest, CheckBulkPermissionsResponse, CheckPermissionRequest,
+    CheckPermissionResponse, Cursor, DeleteRelationshipsRequest, DeleteRelationshipsResponse,
+    ExpandPermissionTreeRequest, ExpandPermissionTreeResponse, LookupPermissionship,
+    LookupResourcesRequest, LookupResourcesResponse, LookupSubjectsRequest, LookupSubjectsResponse,
+    ReadRelationshipsRequest, ReadRelationshipsResponse, ReadSchemaRequest, ReadSchemaResponse,
+    ResolvedSubject, WatchRequest, WatchResponse, WriteRelationshipsRequest,
+    WriteRelationshipsResponse, WriteSchemaRequest, WriteSchemaResponse,
+};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{BroadcastStream, TcpListenerStream};
+use tokio_stream::{Stream, StreamExt};
+use tonic::service::Routes;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+use crate::SpicedbClient;
+use store::{matches_filter, Change, State, Subject};
+
+/// Number of changes buffered for each watcher before it lags behind.
+const WATCH_CAPACITY: usize = 1024;
+
+type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T, Status>> + Send>>;
+
+#[derive(Debug)]
+struct Shared {
+    state: Mutex<State>,
+    changes: broadcast::Sender<Change>,
+}
+
+/// In-memory fake SpiceDB server. Clones share the same data.
+///
+/// Caveats are ignored, and wildcard lookups return every known subject
+/// besides `*`; see the [module documentation](self) for details.
+#[derive(Clone, Debug)]
+pub struct FakeSpicedb {
+    shared: Arc<Shared>,
+}
+
+impl Default for FakeSpicedb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeSpicedb {
+    /// Create a server without a schema or relationships.
+    pub fn new() -> Self {
+        let (changes, _) = broadcast::channel(WATCH_CAPACITY);
+        Self {
+            shared: Arc::new(Shared {
+                state: Mutex::new(State::default()),
+                changes,
+            }),
+        }
+    }
+
+    /// Create a server with `schema`, returning an error if it cannot be
+    /// parsed.
+    pub fn with_schema(schema: impl ToString) -> Result<Self, Status> {
+        let spicedb = Self::new();
+        spicedb.lock().write_schema(schema.to_string())?;
+        Ok(spicedb)
+    }
+
+    /// The gRPC services, to be served in-process or by a tonic server.
+    pub fn routes(&self) -> Routes {
+        Routes::new(SchemaServiceServer::new(self.clone()))
+            .add_service(PermissionsServiceServer::new(self.clone()))
+            .add_service(WatchServiceServer::new(self.clone()))
+    }
+
+    /// Create a client sending requests to this server in-process.
+    pub fn client(&self) -> SpicedbClient<Routes> {
+        SpicedbClient::with_origin(self.routes(), Uri::from_static("http://spicedb"))
+    }
+
+    /// Serve requests on `listener` in the background.
+    pub fn serve(&self, listener: TcpListener) {
+        let server = Server::builder()
+            .add_routes(self.routes())
+            .serve_with_incoming(TcpListenerStream::new(listener));
+        tokio::spawn(server);
+    }
+
+    /// Serve requests on a random local port in the background, returning
+    /// its address.
+    pub async fn spawn(&self) -> std::io::Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        self.serve(listener);
+        Ok(addr)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Notify watchers of a change. Must be called with the state locked, so
+    /// that watchers see changes in order.
+    fn publish(&self, change: Change) {
+        let _ = self.shared.changes.send(change);
+    }
+}
+
+#[tonic::async_trait]
+impl SchemaService for FakeSpicedb {
+    async fn read_schema(
+        &self,
+        _: Request<ReadSchemaRequest>,
+    ) -> Result<Response<ReadSchemaResponse>, Status> {
+        let state = self.lock();
+        let schema_text = state.schema_text.clone().ok_or_else(|| {
+            Status::not_found("No schema has been defined; please call WriteSchema to start")
+        })?;
+        Ok(Response::new(ReadSchemaResponse {
+            schema_text,
+            read_at: Some(state.token()),
+        }))
+    }
+
+    async fn write_schema(
+        &self,
+        request: Request<WriteSchemaRequest>,
+    ) -> Result<Response<WriteSchemaResponse>, Status> {
+        let mut state = self.lock();
+        state.write_schema(request.into_inner().schema)?;
+        Ok(Response::new(WriteSchemaResponse {
+            written_at: Some(state.token()),
+        }))
+    }
+}
+
+#[tonic::async_trait]
+impl PermissionsService for FakeSpicedb {
+    type ReadRelationshipsStream = ResponseStream<ReadRelationshipsResponse>;
+
+    async fn read_relationships(
+        &self,
+        request: Request<ReadRelationshipsRequest>,
+    ) -> Result<Response<Self::ReadRelationshipsStream>, Status> {
+        let request = request.into_inner();
+        let filter = request.relationship_filter.unwrap_or_default();
+        let cursor = request.optional_cursor.map(|cursor| cursor.token);
+        let limit = match request.optional_limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+
+        let state = self.lock();
+        let read_at = state.token();
+        let responses: Vec<_> = state
+            .read(&filter, cursor.as_deref())
+            .take(limit)
+            .map(|(cursor, relationship)| {
+                Ok(ReadRelationshipsResponse {
+                    read_at: Some(read_at.clone()),
+                    relationship: Some(relationship.clone()),
+                    after_result_cursor: Some(Cursor { token: cursor }),
+                })
+            })
+            .collect();
+        Ok(Response::new(Box::pin(tokio_stream::iter(responses))))
+    }
+
+    async fn write_relationships(
+        &self,
+        request: Request<WriteRelationshipsRequest>,
+    ) -> Result<Response<WriteRelationshipsResponse>, Status> {
+        let request = request.into_inner();
+        let mut state = self.lock();
+        let change = state.write(request.updates, &request.optional_preconditions)?;
+        let written_at = state.token();
+        self.publish(change);
+        Ok(Response::new(WriteRelationshipsResponse {
+            written_at: Some(written_at),
+        }))
+    }
+
+    async fn delete_relationships(
+        &self,
+        request: Request<DeleteRelationshipsRequest>,
+    ) -> Result<Response<DeleteRelationshipsResponse>, Status> {
+        use spicedb_grpc::authzed::api::v1::delete_relationships_response::DeletionProgress;
+
+        let request = request.into_inner();
+        let filter = request.relationship_filter.unwrap_or_default();
+        let mut state = self.lock();
+        let (change, complete) = state.delete(
+            &filter,
+            &request.optional_preconditions,
+            request.optional_limit,
+            request.optional_allow_partial_deletions,
+        )?;
+        let deleted_at = state.token();
+        self.publish(change);
+
+        let deletion_progress = match complete {
+            true => DeletionProgress::Complete,
+            false => DeletionProgress::Partial,
+        };
+        Ok(Response::new(DeleteRelationshipsResponse {
+            deleted_at: Some(deleted_at),
+            deletion_progress: deletion_progress.into(),
+        }))
+    }
+
+    async fn check_permission(
+        &self,
+        request: Request<CheckPermissionRequest>,
+    ) -> Result<Response<CheckPermissionResponse>, Status> {
+        let request = request.into_inner();
+        let resource = request.resource.unwrap_or_default();
+        let subject = Subject::from(request.subject.unwrap_or_default());
+
+        let state = self.lock();
+        let permissionship = match state.check(&resource, &request.permission, &subject)? {
+            true => Permissionship::HasPermission,
+            false => Permissionship::NoPermission,
+        };
+        Ok(Response::new(CheckPermissionResponse {
+            checked_at: Some(state.token()),
+            permissionship: permissionship.into(),
+            ..Default::default()
+        }))
+    }
+
+    async fn check_bulk_permissions(
+        &self,
+        _: Request<CheckBulkPermissionsRequest>,
+    ) -> Result<Response<CheckBulkPermissionsResponse>, Status> {
+        Err(Status::unimplemented(
+            "CheckBulkPermissions is not implemented by the fake server",
+        ))
+    }
+
+    async fn expand_permission_tree(
+        &self,
+        _: Request<ExpandPermissionTreeRequest>,
+    ) -> Result<Response<ExpandPermissionTreeResponse>, Status> {
+        Err(Status::unimplemented(
+            "ExpandPermissionTree is not implemented by the fake server",
+        ))
+    }
+
+    type LookupResourcesStream = ResponseStream<LookupResourcesResponse>;
+
+    async fn lookup_resources(
+        &self,
+        request: Request<LookupResourcesRequest>,
+    ) -> Result<Response<Self::LookupResourcesStream>, Status> {
+        let request = request.into_inner();
+        let subject = Subject::from(request.subject.unwrap_or_default());
+        let after = request.optional_cursor.map(|cursor| cursor.token);
+        let limit = match request.optional_limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+
+        let state = self.lock();
+        let looked_up_at = state.token();
+        let responses: Vec<_> = state
+            .lookup_resources(&request.resource_object_type, &request.permission, &subject)?
+            .into_iter()
+            .filter(|id| after.as_ref().is_none_or(|after| id > after))
+            .take(limit)
+            .map(|resource_object_id| {
+                Ok(LookupResourcesResponse {
+                    looked_up_at: Some(looked_up_at.clone()),
+                    after_result_cursor: Some(Cursor {
+                        token: resource_object_id.clone(),
+                    }),
+                    resource_object_id,
+                    permissionship: LookupPermissionship::HasPermission.into(),
+                    partial_caveat_info: None,
+                })
+            })
+            .collect();
+        Ok(Response::new(Box::pin(tokio_stream::iter(responses))))
+    }
+
+    type LookupSubjectsStream = ResponseStream<LookupSubjectsResponse>;
+
+    async fn lookup_subjects(
+        &self,
+        request: Request<LookupSubjectsRequest>,
+    ) -> Result<Response<Self::LookupSubjectsStream>, Status> {
+        let request = request.into_inner();
+        let resource = request.resource.unwrap_or_default();
+
+        let state = self.lock();
+        let looked_up_at = state.token();
+        let responses: Vec<_> = state
+            .lookup_subjects(
+                &resource,
+                &request.permission,
+                &request.subject_object_type,
+                &request.optional_subject_relation,
+            )?
+            .into_iter()
+            .map(|subject_object_id| {
+                Ok(LookupSubjectsResponse {
+                    looked_up_at: Some(looked_up_at.clone()),
+                    subject: Some(ResolvedSubject {
+                        subject_object_id,
+                        permissionship: LookupPermissionship::HasPermission.into(),
+                        partial_caveat_info: None,
+                    }),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        Ok(Response::new(Box::pin(tokio_stream::iter(responses))))
+    }
+}
+
+#[tonic::async_trait]
+impl WatchService for FakeSpicedb {
+    type WatchStream = ResponseStream<WatchResponse>;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let request = request.into_inner();
+        let start = match &request.optional_start_cursor {
+            Some(cursor) => Some(
+                cursor
+                    .token
+                    .parse::<u64>()
+                    .map_err(|_| Status::invalid_argument("invalid start cursor"))?,
+            ),
+            None => None,
+        };
+
+        // Subscribe with the state locked, so no change is missed between the
+        // history and the subscription.
+        let (history, changes) = {
+            let state = self.lock();
+            let history: Vec<_> = match start {
+                Some(start) => state
+                    .history
+                    .iter()
+                    .filter(|change| change.revision > start)
+                    .cloned()
+                    .collect(),
+                None => vec![],
+            };
+            (history, self.shared.changes.subscribe())
+        };
+
+        let changes = BroadcastStream::new(changes).map(|change| {
+            change.map_err(|_| Status::resource_exhausted("watch fell behind the changes"))
+        });
+        let responses = tokio_stream::iter(history.into_iter().map(Ok))
+            .chain(changes)
+            .filter_map(move |change| match change {
+                Ok(change) => watch_response(&request, change).map(Ok),
+                Err(status) => Some(Err(status)),
+            });
+        Ok(Response::new(Box::pin(responses)))
+    }
+}
+
+/// The updates of a change matching the watch request, if any.
+fn watch_response(request: &WatchRequest, change: Change) -> Option<WatchResponse> {
+    let updates: Vec<_> = change
+        .updates
+        .into_iter()
+        .filter(|update| {
+            let relationship = update.relationship.clone().unwrap_or_default();
+            let resource_type = relationship
+                .resource
+                .as_ref()
+                .map(|resource| resource.object_type.as_str())
+                .unwrap_or_default();
+            (request.optional_object_types.is_empty()
+                || request
+                    .optional_object_types
+                    .iter()
+                    .any(|object_type| object_type == resource_type))
+                && (request.optional_relationship_filters.is_empty()
+                    || request
+                        .optional_relationship_filters
+                        .iter()
+                        .any(|filter| matches_filter(filter, &relationship)))
+        })
+        .collect();
+    if updates.is_empty() {
+        return None;
+    }
+    Some(WatchResponse {
+        updates,
+        changes_through: Some(spicedb_grpc::authzed::api::v1::ZedToken {
+            token: change.revision.to_string(),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use spicedb_grpc::authzed::api::v1::{
+        ErrorReason, ObjectReference, Relationship, RelationshipFilter, SubjectReference,
+    };
+    use tokio::test;
+    use tonic::Code;
+
+    use super::*;
+    use crate::builder::{RelationshipFilterBuilder, WriteRelationshipsRequestBuilder};
+
+    const SCHEMA: &str = r#"
+definition user {}
+
+definition group {
+    relation member: user | group#member
+}
+
+definition folder {
+    relation viewer: user | group#member
+    permission view = viewer
+}
+
+definition document {
+    relation parent: folder
+    relation viewer: user | group#member | user:*
+    relation approved: user
+    relation banned: user
+    permission view = (viewer + parent->view) - banned
+    permission approved_view = view & approved
+}
+"#;
+
+    fn object(object_type: &str, object_id: &str) -> ObjectReference {
+        ObjectReference {
+            object_type: object_type.to_string(),
+            object_id: object_id.to_string(),
+        }
+    }
+
+    fn subject(object_type: &str, object_id: &str) -> SubjectReference {
+        SubjectReference {
+            object: Some(object(object_type, object_id)),
+            optional_relation: String::new(),
+        }
+    }
+
+    async fn check(
+        client: &SpicedbClient<Routes>,
+        document: &str,
+        permission: &str,
+        user: &str,
+    ) -> bool {
+        let response = client
+            .check_permission(CheckPermissionRequest {
+                resource: Some(object("document", document)),
+                permission: permission.to_string(),
+                subject: Some(subject("user", user)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        response.permissionship() == Permissionship::HasPermission
+    }
+
+    #[test]
+    pub async fn test_fake_spicedb() {
+        let spicedb = FakeSpicedb::new();
+        let client = spicedb.client();
+
+        let err = client.read_schema().await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::NotFound));
+        let err = client
+            .write_schema("definition document { permission view = viewer }")
+            .await
+            .unwrap_err();
+        assert_eq!(err.reason(), Some(ErrorReason::SchemaParseError));
+
+        client.write_schema(SCHEMA).await.unwrap();
+        assert_eq!(client.read_schema().await.unwrap().schema_text, SCHEMA);
+
+        let mut watch = client
+            .watch(WatchRequest {
+                optional_object_types: vec!["document".to_string()],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.create_relationship("group", "eng", "member", "user", "alice");
+        request.create_relationship("folder", "f1", "viewer", "group", "eng");
+        request.create_relationship("document", "d1", "parent", "folder", "f1");
+        request.create_relationship("document", "d2", "viewer", "user", "bob");
+        request.create_relationship("document", "d2", "approved", "user", "bob");
+        request.create_relationship("document", "d3", "viewer", "user", "*");
+        request.create_relationship("document", "d3", "banned", "user", "carol");
+        for update in &mut request.updates[1..2] {
+            let relationship = update.relationship.as_mut().unwrap();
+            relationship.subject.as_mut().unwrap().optional_relation = "member".to_string();
+        }
+        client.write_relationships(request).await.unwrap();
+
+        // Arrow through a userset.
+        assert!(check(&client, "d1", "view", "alice").await);
+        assert!(!check(&client, "d1", "view", "bob").await);
+        // Intersection.
+        assert!(check(&client, "d2", "approved_view", "bob").await);
+        assert!(!check(&client, "d1", "approved_view", "alice").await);
+        // Wildcard and exclusion.
+        assert!(check(&client, "d3", "view", "alice").await);
+        assert!(!check(&client, "d3", "view", "carol").await);
+
+        let err = client
+            .check_permission(CheckPermissionRequest {
+                resource: Some(object("document", "d1")),
+                permission: "edit".to_string(),
+                subject: Some(subject("user", "alice")),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Some(Code::FailedPrecondition));
+
+        // Preconditions.
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.update_relationship("document", "d1", "banned", "user", "alice");
+        request
+            .not_match_precondition()
+            .resource_type("document")
+            .relation("parent");
+        let err = client.write_relationships(request).await.unwrap_err();
+        assert_eq!(
+            err.reason(),
+            Some(ErrorReason::WriteOrDeletePreconditionFailure)
+        );
+
+        let mut request = ReadRelationshipsRequest::default();
+        request.resource_type("document").resource_id("d2");
+        let mut responses = client.read_relationships(request).await.unwrap();
+        let mut relations = vec![];
+        while let Some(response) = responses.message().await.unwrap() {
+            relations.push(response.relationship.unwrap().relation);
+        }
+        assert_eq!(relations, ["approved", "viewer"]);
+
+        let mut responses = client
+            .lookup_resources(LookupResourcesRequest {
+                resource_object_type: "document".to_string(),
+                permission: "view".to_string(),
+                subject: Some(subject("user", "alice")),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let mut resources = vec![];
+        while let Some(response) = responses.message().await.unwrap() {
+            resources.push(response.resource_object_id);
+        }
+        assert_eq!(resources, ["d1", "d3"]);
+
+        let mut responses = client
+            .lookup_subjects(LookupSubjectsRequest {
+                resource: Some(object("document", "d1")),
+                permission: "view".to_string(),
+                subject_object_type: "user".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let response = responses.message().await.unwrap().unwrap();
+        assert_eq!(response.subject.unwrap().subject_object_id, "alice");
+        assert!(responses.message().await.unwrap().is_none());
+
+        let mut filter = RelationshipFilter::new();
+        filter.resource_type("document").resource_id("d2");
+        client
+            .delete_relationships(DeleteRelationshipsRequest {
+                relationship_filter: Some(filter),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(!check(&client, "d2", "view", "bob").await);
+
+        // The group membership is filtered out of the watch.
+        let response = watch.message().await.unwrap().unwrap();
+        let relationships: Vec<Relationship> = response
+            .updates
+            .into_iter()
+            .filter_map(|update| update.relationship)
+            .collect();
+        assert_eq!(relationships.len(), 5);
+        let response = watch.message().await.unwrap().unwrap();
+        assert_eq!(response.updates.len(), 2);
+    }
+
+    #[test]
+    pub async fn test_rejected_write() {
+        let spicedb = FakeSpicedb::with_schema(SCHEMA).unwrap();
+        let client = spicedb.client();
+
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.create_relationship("document", "d1", "viewer", "user", "alice");
+        client.write_relationships(request).await.unwrap();
+        let revision = spicedb.lock().revision;
+
+        // An invalid update after a valid one must not apply either.
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.update_relationship("document", "d1", "viewer", "user", "bob");
+        request.delete_relationship("document", "d1", "viewer", "user", "alice");
+        request.updates[1].operation = 0;
+        let err = client.write_relationships(request).await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::InvalidArgument));
+
+        let mut request = WriteRelationshipsRequest::new([]);
+        request.update_relationship("document", "d1", "viewer", "user", "bob");
+        request.delete_relationship("document", "d1", "viewer", "user", "bob");
+        let err = client.write_relationships(request).await.unwrap_err();
+        assert_eq!(err.code(), Some(Code::InvalidArgument));
+
+        assert!(check(&client, "d1", "view", "alice").await);
+        assert!(!check(&client, "d1", "view", "bob").await);
+        let state = spicedb.lock();
+        assert_eq!(state.revision, revision);
+        assert_eq!(state.history.len(), 1);
+    }
+
+    #[test]
+    pub async fn test_spawn() {
+        let spicedb = FakeSpicedb::with_schema("definition user {}").unwrap();
+        let addr = spicedb.spawn().await.unwrap();
+
+        let client = SpicedbClient::from_url_and_preshared_key(format!("http://{addr}"), "spicedb")
+            .await
+            .unwrap();
+        let response = client.read_schema().await.unwrap();
+        assert_eq!(response.schema_text, "definition user {}");
+    }
+}
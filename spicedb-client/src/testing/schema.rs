@@ -0,0 +1,173 @@
+//! The subset of the schema language evaluated by the fake server.
+
+use std::collections::HashMap;
+
+use crate::schema::ast::{self, ArrowFunction};
+use crate::schema::{check, Diagnostic, SubjectTypeKind};
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Schema {
+    pub(crate) definitions: HashMap<String, Definition>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Definition {
+    pub(crate) relations: HashMap<String, Vec<AllowedSubject>>,
+    pub(crate) permissions: HashMap<String, Expr>,
+}
+
+/// A subject type allowed on a relation, such as `user`, `group#member` or
+/// `user:*`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct AllowedSubject {
+    pub(crate) definition: String,
+    pub(crate) relation: String,
+    pub(crate) wildcard: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Expr {
+    Nil,
+    /// A relation or permission of the same object.
+    Ref(String),
+    /// `tupleset->computed`, or `tupleset.all(computed)` if `all` is set.
+    Arrow {
+        tupleset: String,
+        computed: String,
+        all: bool,
+    },
+    Union(Vec<Expr>),
+    Intersection(Vec<Expr>),
+    Exclusion(Box<Expr>, Box<Expr>),
+}
+
+/// Parse a schema, returning the first error if it is invalid.
+pub(crate) fn parse(text: &str) -> Result<Schema, String> {
+    let ast = crate::schema::parse(text).map_err(|err| err.to_string())?;
+    if let Some(error) = check(&ast).into_iter().find(Diagnostic::is_error) {
+        return Err(error.message);
+    }
+
+    let mut schema = Schema::default();
+    for definition in ast.definitions() {
+        let mut lowered = Definition::default();
+        for member in &definition.members {
+            let name = member.name().to_string();
+            match member {
+                ast::Member::Relation(relation) => {
+                    let subjects = relation.subject_types.iter().map(lower_subject).collect();
+                    lowered.relations.insert(name, subjects);
+                }
+                ast::Member::Permission(permission) => {
+                    lowered
+                        .permissions
+                        .insert(name, lower_expr(&permission.expression));
+                }
+            }
+        }
+        schema
+            .definitions
+            .insert(definition.name.to_string(), lowered);
+    }
+    Ok(schema)
+}
+
+fn lower_subject(subject_type: &ast::SubjectType) -> AllowedSubject {
+    AllowedSubject {
+        definition: subject_type.definition.to_string(),
+        relation: match &subject_type.kind {
+            SubjectTypeKind::Relation(relation) => relation.clone(),
+            _ => String::new(),
+        },
+        wildcard: subject_type.kind == SubjectTypeKind::Wildcard,
+    }
+}
+
+fn lower_expr(expr: &ast::Expr) -> Expr {
+    match expr {
+        ast::Expr::Nil(_) => Expr::Nil,
+        ast::Expr::Ref(name) => Expr::Ref(name.to_string()),
+        ast::Expr::Arrow {
+            tupleset,
+            function,
+            computed,
+            ..
+        } => Expr::Arrow {
+            tupleset: tupleset.to_string(),
+            computed: computed.to_string(),
+            all: *function == ArrowFunction::All,
+        },
+        ast::Expr::Union { operands, .. } => Expr::Union(operands.iter().map(lower_expr).collect()),
+        ast::Expr::Intersection { operands, .. } => {
+            Expr::Intersection(operands.iter().map(lower_expr).collect())
+        }
+        ast::Expr::Exclusion { base, excluded, .. } => {
+            Expr::Exclusion(Box::new(lower_expr(base)), Box::new(lower_expr(excluded)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let schema = parse(
+            r#"
+            /** a user */
+            definition user {}
+
+            caveat on_weekday(day int) {
+                day < 6 && day > 0
+            }
+
+            definition group {
+                relation member: user | group#member | user:* with on_weekday
+            }
+
+            definition document {
+                relation parent: document
+                relation viewer: user | group#member
+                relation banned: user
+                // comment
+                permission view = viewer + parent->view - banned & viewer
+                permission all_parents = parent.all(view)
+                permission none = nil
+            }
+            "#,
+        )
+        .unwrap();
+
+        let group = &schema.definitions["group"];
+        assert_eq!(group.relations["member"].len(), 3);
+        assert!(group.relations["member"][2].wildcard);
+
+        let document = &schema.definitions["document"];
+        assert_eq!(
+            document.permissions["view"],
+            Expr::Exclusion(
+                Box::new(Expr::Union(vec![
+                    Expr::Ref("viewer".to_string()),
+                    Expr::Arrow {
+                        tupleset: "parent".to_string(),
+                        computed: "view".to_string(),
+                        all: false,
+                    },
+                ])),
+                Box::new(Expr::Intersection(vec![
+                    Expr::Ref("banned".to_string()),
+                    Expr::Ref("viewer".to_string()),
+                ])),
+            )
+        );
+
+        let err = parse("definition document { permission view = viewer }").unwrap_err();
+        assert_eq!(
+            err,
+            "relation/permission `viewer` not found under definition `document`"
+        );
+        assert!(parse("definition document { relation viewer: user }").is_err());
+        assert!(parse("definition document {").is_err());
+    }
+}
@@ -0,0 +1,589 @@
+//! In-memory relationships and permission evaluation of the fake server.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use prost::Message;
+use spicedb_grpc::authzed::api::v1::{
+    precondition, relationship_update::Operation, subject_filter::RelationFilter, ErrorReason,
+    ObjectReference, Precondition, Relationship, RelationshipFilter, RelationshipUpdate,
+    SubjectFilter, SubjectReference, ZedToken,
+};
+use spicedb_grpc::google::rpc::{ErrorInfo, Status as RpcStatus};
+use tonic::{Code, Status};
+
+use super::schema::{self, Expr, Schema};
+
+/// Maximum depth of nested relations and permissions, as in SpiceDB.
+const MAXIMUM_DEPTH: u32 = 50;
+
+/// Relationships are ordered by resource, relation and subject.
+type Key = (String, String, String, String, String, String);
+
+/// Relationships written at a revision.
+#[derive(Clone, Debug)]
+pub(crate) struct Change {
+    pub(crate) revision: u64,
+    pub(crate) updates: Vec<RelationshipUpdate>,
+}
+
+/// A subject, where `relation` is empty for objects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Subject {
+    pub(crate) object_type: String,
+    pub(crate) object_id: String,
+    pub(crate) relation: String,
+}
+
+impl From<SubjectReference> for Subject {
+    fn from(subject: SubjectReference) -> Self {
+        let object = subject.object.unwrap_or_default();
+        Self {
+            object_type: object.object_type,
+            object_id: object.object_id,
+            relation: subject.optional_relation,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct State {
+    pub(crate) schema_text: Option<String>,
+    schema: Schema,
+    relationships: BTreeMap<Key, Relationship>,
+    pub(crate) revision: u64,
+    pub(crate) history: Vec<Change>,
+}
+
+impl State {
+    pub(crate) fn token(&self) -> ZedToken {
+        ZedToken {
+            token: self.revision.to_string(),
+        }
+    }
+
+    pub(crate) fn write_schema(&mut self, text: String) -> Result<(), Status> {
+        self.schema = schema::parse(&text).map_err(|message| {
+            status(
+                Code::InvalidArgument,
+                message,
+                ErrorReason::SchemaParseError,
+                &[],
+            )
+        })?;
+        self.schema_text = Some(text);
+        self.revision += 1;
+        Ok(())
+    }
+
+    /// The relationships matching `filter`, after the relationship
+    /// serialized in `cursor` if set.
+    pub(crate) fn read(
+        &self,
+        filter: &RelationshipFilter,
+        cursor: Option<&str>,
+    ) -> impl Iterator<Item = (String, &Relationship)> {
+        let after = cursor.map(decode_cursor);
+        let filter = filter.clone();
+        self.relationships
+            .iter()
+            .filter(move |(key, _)| after.as_ref().is_none_or(|after| *key > after))
+            .filter(move |(_, relationship)| matches_filter(&filter, relationship))
+            .map(|(key, relationship)| (encode_cursor(key), relationship))
+    }
+
+    /// Apply updates atomically if the preconditions are satisfied, returning
+    /// the new revision.
+    pub(crate) fn write(
+        &mut self,
+        updates: Vec<RelationshipUpdate>,
+        preconditions: &[Precondition],
+    ) -> Result<Change, Status> {
+        self.check_preconditions(preconditions)?;
+        let mut seen = BTreeSet::new();
+        for update in &updates {
+            let relationship = update.relationship.as_ref().ok_or_else(|| {
+                Status::invalid_argument("relationship update is missing a relationship")
+            })?;
+            if update.operation() == Operation::Unspecified {
+                return Err(Status::invalid_argument("unspecified update operation"));
+            }
+            self.validate(relationship)?;
+            if !seen.insert(key(relationship)) {
+                return Err(Status::invalid_argument(format!(
+                    "found more than one update with relationship `{}` in this request; a \
+                     relationship can only be specified in an update once per request",
+                    describe(relationship)
+                )));
+            }
+            if update.operation() == Operation::Create
+                && self.relationships.contains_key(&key(relationship))
+            {
+                return Err(Status::already_exists(format!(
+                    "could not CREATE relationship `{}`, as it already existed",
+                    describe(relationship)
+                )));
+            }
+        }
+
+        for update in &updates {
+            let relationship = update.relationship.clone().unwrap_or_default();
+            match update.operation() {
+                Operation::Create | Operation::Touch => {
+                    self.relationships.insert(key(&relationship), relationship);
+                }
+                Operation::Delete => {
+                    self.relationships.remove(&key(&relationship));
+                }
+                Operation::Unspecified => unreachable!("rejected above"),
+            }
+        }
+        Ok(self.commit(updates))
+    }
+
+    /// Delete the relationships matching `filter` if the preconditions are
+    /// satisfied, returning whether all of them were deleted.
+    pub(crate) fn delete(
+        &mut self,
+        filter: &RelationshipFilter,
+        preconditions: &[Precondition],
+        limit: u32,
+        allow_partial: bool,
+    ) -> Result<(Change, bool), Status> {
+        self.check_preconditions(preconditions)?;
+        if !self.schema.definitions.contains_key(&filter.resource_type)
+            && !filter.resource_type.is_empty()
+        {
+            return Err(unknown_definition(&filter.resource_type));
+        }
+
+        let mut keys: Vec<_> = self
+            .relationships
+            .iter()
+            .filter(|(_, relationship)| matches_filter(filter, relationship))
+            .map(|(key, _)| key.clone())
+            .collect();
+        let complete = limit == 0 || keys.len() <= limit as usize;
+        if !complete {
+            if !allow_partial {
+                return Err(Status::failed_precondition(format!(
+                    "found more than {limit} relationships to be deleted and partial deletion \
+                     was not requested"
+                )));
+            }
+            keys.truncate(limit as usize);
+        }
+
+        let updates = keys
+            .iter()
+            .filter_map(|key| self.relationships.remove(key))
+            .map(|relationship| RelationshipUpdate {
+                operation: Operation::Delete.into(),
+                relationship: Some(relationship),
+            })
+            .collect();
+        Ok((self.commit(updates), complete))
+    }
+
+    fn commit(&mut self, updates: Vec<RelationshipUpdate>) -> Change {
+        self.revision += 1;
+        let change = Change {
+            revision: self.revision,
+            updates,
+        };
+        self.history.push(change.clone());
+        change
+    }
+
+    fn check_preconditions(&self, preconditions: &[Precondition]) -> Result<(), Status> {
+        for precondition in preconditions {
+            let filter = precondition.filter.clone().unwrap_or_default();
+            let matched = self
+                .relationships
+                .values()
+                .any(|relationship| matches_filter(&filter, relationship));
+            let satisfied = match precondition.operation() {
+                precondition::Operation::MustMatch => matched,
+                precondition::Operation::MustNotMatch => !matched,
+                precondition::Operation::Unspecified => {
+                    return Err(Status::invalid_argument(
+                        "unspecified precondition operation",
+                    ))
+                }
+            };
+            if !satisfied {
+                return Err(status(
+                    Code::FailedPrecondition,
+                    "unable to satisfy write precondition".to_string(),
+                    ErrorReason::WriteOrDeletePreconditionFailure,
+                    &[("precondition_resource_type", filter.resource_type)],
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the relationship is allowed by the schema.
+    fn validate(&self, relationship: &Relationship) -> Result<(), Status> {
+        let resource = relationship.resource.clone().unwrap_or_default();
+        let subject = Subject::from(relationship.subject.clone().unwrap_or_default());
+        let definition = self
+            .schema
+            .definitions
+            .get(&resource.object_type)
+            .ok_or_else(|| unknown_definition(&resource.object_type))?;
+        let Some(allowed) = definition.relations.get(&relationship.relation) else {
+            return Err(unknown_relation(
+                &resource.object_type,
+                &relationship.relation,
+            ));
+        };
+
+        let wildcard = subject.object_id == "*";
+        let is_allowed = allowed.iter().any(|allowed| {
+            allowed.definition == subject.object_type
+                && allowed.wildcard == wildcard
+                && (wildcard || allowed.relation == subject.relation)
+        });
+        if !is_allowed {
+            return Err(Status::invalid_argument(format!(
+                "subjects of type `{}` are not allowed on relation `{}#{}`",
+                describe_subject_type(&subject),
+                resource.object_type,
+                relationship.relation
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether `subject` has `permission` on the resource.
+    pub(crate) fn check(
+        &self,
+        resource: &ObjectReference,
+        permission: &str,
+        subject: &Subject,
+    ) -> Result<bool, Status> {
+        self.has(
+            &resource.object_type,
+            &resource.object_id,
+            permission,
+            subject,
+            0,
+        )
+    }
+
+    /// The IDs of the resources of `resource_type` on which `subject` has
+    /// `permission`, in order.
+    pub(crate) fn lookup_resources(
+        &self,
+        resource_type: &str,
+        permission: &str,
+        subject: &Subject,
+    ) -> Result<Vec<String>, Status> {
+        let mut found = Vec::new();
+        for object_id in self.object_ids(resource_type) {
+            if self.has(resource_type, &object_id, permission, subject, 0)? {
+                found.push(object_id);
+            }
+        }
+        Ok(found)
+    }
+
+    /// The IDs of the subjects of `subject_type` that have `permission` on
+    /// the resource, in order. `*` is included if all subjects do, alongside
+    /// every subject ID in the store.
+    pub(crate) fn lookup_subjects(
+        &self,
+        resource: &ObjectReference,
+        permission: &str,
+        subject_type: &str,
+        subject_relation: &str,
+    ) -> Result<Vec<String>, Status> {
+        let mut found = Vec::new();
+        for object_id in self.object_ids(subject_type) {
+            let subject = Subject {
+                object_type: subject_type.to_string(),
+                object_id,
+                relation: subject_relation.to_string(),
+            };
+            if self.check(resource, permission, &subject)? {
+                found.push(subject.object_id);
+            }
+        }
+        Ok(found)
+    }
+
+    /// The IDs of the objects of `object_type` referenced by a relationship.
+    fn object_ids(&self, object_type: &str) -> BTreeSet<String> {
+        let mut ids = BTreeSet::new();
+        for (resource_type, resource_id, _, subject_type, subject_id, _) in
+            self.relationships.keys()
+        {
+            if resource_type == object_type {
+                ids.insert(resource_id.clone());
+            }
+            if subject_type == object_type {
+                ids.insert(subject_id.clone());
+            }
+        }
+        ids
+    }
+
+    /// The subjects of the relation of an object.
+    fn subjects<'a>(
+        &'a self,
+        object_type: &str,
+        object_id: &str,
+        relation: &str,
+    ) -> impl Iterator<Item = Subject> + 'a {
+        let start: Key = (
+            object_type.to_string(),
+            object_id.to_string(),
+            relation.to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        let prefix = start.clone();
+        self.relationships
+            .range(start..)
+            .take_while(move |(key, _)| {
+                (&key.0, &key.1, &key.2) == (&prefix.0, &prefix.1, &prefix.2)
+            })
+            .map(|(key, _)| Subject {
+                object_type: key.3.clone(),
+                object_id: key.4.clone(),
+                relation: key.5.clone(),
+            })
+    }
+
+    fn has(
+        &self,
+        object_type: &str,
+        object_id: &str,
+        name: &str,
+        subject: &Subject,
+        depth: u32,
+    ) -> Result<bool, Status> {
+        if depth > MAXIMUM_DEPTH {
+            return Err(status(
+                Code::ResourceExhausted,
+                "max depth exceeded: this usually indicates a recursive or too deep data \
+                 dependency"
+                    .to_string(),
+                ErrorReason::MaximumDepthExceeded,
+                &[("maximum_depth_allowed", MAXIMUM_DEPTH.to_string())],
+            ));
+        }
+        if subject.object_type == object_type
+            && subject.object_id == object_id
+            && subject.relation == name
+        {
+            return Ok(true);
+        }
+
+        let definition = self
+            .schema
+            .definitions
+            .get(object_type)
+            .ok_or_else(|| unknown_definition(object_type))?;
+        if definition.relations.contains_key(name) {
+            for candidate in self.subjects(object_type, object_id, name) {
+                let wildcard = candidate.object_id == "*" && subject.relation.is_empty();
+                if candidate.object_type == subject.object_type
+                    && candidate.relation == subject.relation
+                    && (candidate.object_id == subject.object_id || wildcard)
+                {
+                    return Ok(true);
+                }
+                if !candidate.relation.is_empty()
+                    && self.has(
+                        &candidate.object_type,
+                        &candidate.object_id,
+                        &candidate.relation,
+                        subject,
+                        depth + 1,
+                    )?
+                {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+        match definition.permissions.get(name) {
+            Some(expr) => self.eval(object_type, object_id, expr, subject, depth + 1),
+            None => Err(unknown_relation(object_type, name)),
+        }
+    }
+
+    fn eval(
+        &self,
+        object_type: &str,
+        object_id: &str,
+        expr: &Expr,
+        subject: &Subject,
+        depth: u32,
+    ) -> Result<bool, Status> {
+        match expr {
+            Expr::Nil => Ok(false),
+            Expr::Ref(name) => self.has(object_type, object_id, name, subject, depth),
+            Expr::Arrow {
+                tupleset,
+                computed,
+                all,
+            } => {
+                let mut found = false;
+                for target in self.subjects(object_type, object_id, tupleset) {
+                    let has = self
+                        .schema
+                        .definitions
+                        .get(&target.object_type)
+                        .is_some_and(|definition| {
+                            definition.relations.contains_key(computed)
+                                || definition.permissions.contains_key(computed)
+                        })
+                        && self.has(
+                            &target.object_type,
+                            &target.object_id,
+                            computed,
+                            subject,
+                            depth + 1,
+                        )?;
+                    match (has, all) {
+                        (true, false) => return Ok(true),
+                        (false, true) => return Ok(false),
+                        _ => found = true,
+                    }
+                }
+                Ok(*all && found)
+            }
+            Expr::Union(operands) => {
+                for operand in operands {
+                    if self.eval(object_type, object_id, operand, subject, depth)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Expr::Intersection(operands) => {
+                for operand in operands {
+                    if !self.eval(object_type, object_id, operand, subject, depth)? {
+                        return Ok(false);
+                    }
+                }
+                Ok(true)
+            }
+            Expr::Exclusion(base, excluded) => {
+                Ok(self.eval(object_type, object_id, base, subject, depth)?
+                    && !self.eval(object_type, object_id, excluded, subject, depth)?)
+            }
+        }
+    }
+}
+
+/// Whether the relationship matches the filter. Empty fields match anything.
+pub(crate) fn matches_filter(filter: &RelationshipFilter, relationship: &Relationship) -> bool {
+    let resource = relationship.resource.clone().unwrap_or_default();
+    let subject = Subject::from(relationship.subject.clone().unwrap_or_default());
+    let matches = |expected: &str, actual: &str| expected.is_empty() || expected == actual;
+
+    matches(&filter.resource_type, &resource.object_type)
+        && matches(&filter.optional_resource_id, &resource.object_id)
+        && resource
+            .object_id
+            .starts_with(&filter.optional_resource_id_prefix)
+        && matches(&filter.optional_relation, &relationship.relation)
+        && filter
+            .optional_subject_filter
+            .as_ref()
+            .is_none_or(|filter| matches_subject_filter(filter, &subject))
+}
+
+fn matches_subject_filter(filter: &SubjectFilter, subject: &Subject) -> bool {
+    filter.subject_type == subject.object_type
+        && (filter.optional_subject_id.is_empty()
+            || filter.optional_subject_id == subject.object_id)
+        && filter
+            .optional_relation
+            .as_ref()
+            .is_none_or(|RelationFilter { relation }| *relation == subject.relation)
+}
+
+fn key(relationship: &Relationship) -> Key {
+    let resource = relationship.resource.clone().unwrap_or_default();
+    let subject = Subject::from(relationship.subject.clone().unwrap_or_default());
+    (
+        resource.object_type,
+        resource.object_id,
+        relationship.relation.clone(),
+        subject.object_type,
+        subject.object_id,
+        subject.relation,
+    )
+}
+
+fn encode_cursor(key: &Key) -> String {
+    [&key.0, &key.1, &key.2, &key.3, &key.4, &key.5]
+        .map(String::as_str)
+        .join("\n")
+}
+
+fn decode_cursor(cursor: &str) -> Key {
+    let mut parts = cursor.split('\n').map(str::to_string);
+    let mut next = || parts.next().unwrap_or_default();
+    (next(), next(), next(), next(), next(), next())
+}
+
+fn describe(relationship: &Relationship) -> String {
+    let (resource_type, resource_id, relation, subject_type, subject_id, subject_relation) =
+        key(relationship);
+    let mut text = format!("{resource_type}:{resource_id}#{relation}@{subject_type}:{subject_id}");
+    if !subject_relation.is_empty() {
+        text = format!("{text}#{subject_relation}");
+    }
+    text
+}
+
+fn describe_subject_type(subject: &Subject) -> String {
+    match (subject.object_id.as_str(), subject.relation.as_str()) {
+        ("*", _) => format!("{}:*", subject.object_type),
+        (_, "") => subject.object_type.clone(),
+        (_, relation) => format!("{}#{relation}", subject.object_type),
+    }
+}
+
+fn unknown_definition(definition: &str) -> Status {
+    Status::failed_precondition(format!("object definition `{definition}` not found"))
+}
+
+fn unknown_relation(definition: &str, relation: &str) -> Status {
+    status(
+        Code::FailedPrecondition,
+        format!("relation/permission `{relation}` not found under definition `{definition}`"),
+        ErrorReason::UnknownRelationOrPermission,
+        &[
+            ("definition_name", definition.to_string()),
+            ("relation_or_permission_name", relation.to_string()),
+        ],
+    )
+}
+
+/// A status with a SpiceDB `ErrorInfo` detail, as returned by SpiceDB.
+fn status(code: Code, message: String, reason: ErrorReason, metadata: &[(&str, String)]) -> Status {
+    let info = ErrorInfo {
+        reason: reason.as_str_name().to_string(),
+        domain: "authzed.com".to_string(),
+        metadata: metadata
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect(),
+    };
+    let details = RpcStatus {
+        code: code as i32,
+        message: message.clone(),
+        details: vec![prost_types::Any {
+            type_url: "type.googleapis.com/google.rpc.ErrorInfo".to_string(),
+            value: info.encode_to_vec(),
+        }],
+    };
+    Status::with_details(code, message, details.encode_to_vec().into())
+}
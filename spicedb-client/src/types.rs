@@ -8,3 +8,72 @@ pub type ContextValue = Kind;
 pub type ConsistencyRequirement = Requirement;
 pub type RelationshipUpdateOperation = relationship_update::Operation;
 pub type PreconditionOperation = precondition::Operation;
+
+/// Result of counting the relationships matching a registered counter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RelationshipCount {
+    /// The counter was registered recently and its count is not available
+    /// yet.
+    StillCalculating,
+    /// The count, and the ZedToken at which it applies.
+    Value(ReadCounterValue),
+    /// The response had no result known to this client, which may be sent by
+    /// a newer server.
+    Unknown,
+}
+
+impl From<ExperimentalCountRelationshipsResponse> for RelationshipCount {
+    fn from(response: ExperimentalCountRelationshipsResponse) -> Self {
+        use experimental_count_relationships_response::CounterResult;
+
+        match response.counter_result {
+            Some(CounterResult::CounterStillCalculating(_)) => RelationshipCount::StillCalculating,
+            Some(CounterResult::ReadCounterValue(value)) => RelationshipCount::Value(value),
+            None => RelationshipCount::Unknown,
+        }
+    }
+}
+
+impl RelationshipCount {
+    /// The number of relationships, if the count is available.
+    pub fn count(&self) -> Option<u64> {
+        match self {
+            RelationshipCount::StillCalculating | RelationshipCount::Unknown => None,
+            RelationshipCount::Value(value) => Some(value.relationship_count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_relationship_count() {
+        use experimental_count_relationships_response::CounterResult;
+
+        let response = ExperimentalCountRelationshipsResponse {
+            counter_result: Some(CounterResult::CounterStillCalculating(true)),
+        };
+        let count = RelationshipCount::from(response);
+        assert_eq!(count, RelationshipCount::StillCalculating);
+        assert_eq!(count.count(), None);
+
+        let response = ExperimentalCountRelationshipsResponse {
+            counter_result: Some(CounterResult::ReadCounterValue(ReadCounterValue {
+                relationship_count: 42,
+                read_at: Some(ZedToken {
+                    token: "token".to_string(),
+                }),
+            })),
+        };
+        assert_eq!(RelationshipCount::from(response).count(), Some(42));
+
+        let response = ExperimentalCountRelationshipsResponse {
+            counter_result: None,
+        };
+        let count = RelationshipCount::from(response);
+        assert_eq!(count, RelationshipCount::Unknown);
+        assert_eq!(count.count(), None);
+    }
+}
@@ -0,0 +1,71 @@
+//! Connections over Unix domain sockets, selected with `unix:///path` URLs.
+
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use http::Uri;
+use hyper_util::rt::TokioIo;
+use tokio::net::UnixStream;
+use tower::Service;
+
+/// URL of the endpoint for Unix domain socket connections. Only used for the
+/// `:authority` of requests, the connection always goes to the socket.
+pub(crate) const UNIX_ENDPOINT_URL: &str = "http://localhost";
+
+/// The socket path of a `unix:///path` or `unix:path` URL.
+pub(crate) fn unix_socket_path(url: &[u8]) -> Option<PathBuf> {
+    let url = std::str::from_utf8(url).ok()?;
+    let path = url
+        .strip_prefix("unix://")
+        .or_else(|| url.strip_prefix("unix:"))?;
+    Some(PathBuf::from(path))
+}
+
+/// Connector opening a connection to the socket at `path` whenever the
+/// channel needs one.
+#[derive(Clone, Debug)]
+pub(crate) struct UnixConnector {
+    path: PathBuf,
+}
+
+impl UnixConnector {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl Service<Uri> for UnixConnector {
+    type Response = TokioIo<UnixStream>;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = io::Result<Self::Response>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Uri) -> Self::Future {
+        let path = self.path.clone();
+        Box::pin(async move { Ok(TokioIo::new(UnixStream::connect(path).await?)) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unix_socket_path() {
+        assert_eq!(
+            unix_socket_path(b"unix:///run/spicedb.sock"),
+            Some(PathBuf::from("/run/spicedb.sock"))
+        );
+        assert_eq!(
+            unix_socket_path(b"unix:spicedb.sock"),
+            Some(PathBuf::from("spicedb.sock"))
+        );
+        assert_eq!(unix_socket_path(b"http://localhost:50051"), None);
+    }
+}
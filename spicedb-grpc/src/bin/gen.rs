@@ -2,13 +2,21 @@ use std::io::Result;
 
 fn main() -> Result<()> {
     tonic_build::configure()
-        .build_server(false)
+        .server_mod_attribute(".", r#"#[cfg(feature = "server")]"#)
         .out_dir("src/gen")
-        .compile(
+        .type_attribute("authzed.api.v1.ExpRelationReference", "#[derive(Eq, Hash)]")
+        .compile_protos(
             &[
+                "authzed/api/v1/error_reason.proto",
+                "authzed/api/v1/experimental_service.proto",
                 "authzed/api/v1/permission_service.proto",
                 "authzed/api/v1/schema_service.proto",
                 "authzed/api/v1/watch_service.proto",
+                "authzed/api/materialize/v0/watchpermissions.proto",
+                "authzed/api/materialize/v0/watchpermissionsets.proto",
+                "authzed/api/v1alpha1/watchresources_service.proto",
+                "authzed/api/v0/developer.proto",
+                "google/rpc/error_details.proto",
             ],
             &["proto"],
         )?;
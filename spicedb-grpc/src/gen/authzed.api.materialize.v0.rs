@@ -0,0 +1,1170 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPermissionsRequest {
+    /// permissions is a list of permissions to watch for changes. At least one permission must be specified, and it must
+    /// be a subset or equal to the permissions that were enabled for the service.
+    #[prost(message, repeated, tag = "1")]
+    pub permissions: ::prost::alloc::vec::Vec<WatchedPermission>,
+    /// optional_starting_after is the revision token to start watching from. If not provided, the stream
+    /// will start from the current revision at the moment of the request.
+    #[prost(message, optional, tag = "2")]
+    pub optional_starting_after: ::core::option::Option<super::super::v1::ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchedPermission {
+    /// resource_type is the type of the resource to watch for changes.
+    #[prost(string, tag = "1")]
+    pub resource_type: ::prost::alloc::string::String,
+    /// permission is the permission to watch for changes.
+    #[prost(string, tag = "2")]
+    pub permission: ::prost::alloc::string::String,
+    /// subject_type is the type of the subject to watch for changes.
+    #[prost(string, tag = "3")]
+    pub subject_type: ::prost::alloc::string::String,
+    /// optional_subject_relation is the relation on the subject to watch for changes.
+    #[prost(string, tag = "4")]
+    pub optional_subject_relation: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPermissionsResponse {
+    #[prost(oneof = "watch_permissions_response::Response", tags = "1, 2")]
+    pub response: ::core::option::Option<watch_permissions_response::Response>,
+}
+/// Nested message and enum types in `WatchPermissionsResponse`.
+pub mod watch_permissions_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// change is the computed permission delta that has occurred as result of a mutation in origin SpiceDB.
+        /// The consumer should apply this change to the current state of the computed permissions in their target system.
+        /// Once an event arrives with completed_revision instead, the consumer shall consider there are not more changes
+        /// originating from that revision.
+        ///
+        /// The consumer should keep track of the revision in order to resume streaming in the event of consumer restarts.
+        #[prost(message, tag = "1")]
+        Change(super::PermissionChange),
+        /// completed_revision is the revision token that indicates all changes originating from a revision have been
+        /// streamed and thus the revision should be considered completed. It may also be
+        /// received without accompanying set of changes, indicating that a mutation in the origin SpiceDB cluster did
+        /// not yield any effective changes in the computed permissions
+        #[prost(message, tag = "2")]
+        CompletedRevision(super::super::super::v1::ZedToken),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionChange {
+    /// revision represents the revision at which the change occurred.
+    #[prost(message, optional, tag = "1")]
+    pub revision: ::core::option::Option<super::super::v1::ZedToken>,
+    /// resource is the resource that the permission change is related to.
+    #[prost(message, optional, tag = "2")]
+    pub resource: ::core::option::Option<super::super::v1::ObjectReference>,
+    /// permission is the permission that has changed.
+    #[prost(string, tag = "3")]
+    pub permission: ::prost::alloc::string::String,
+    /// subject is the subject that the permission change is related to.
+    #[prost(message, optional, tag = "4")]
+    pub subject: ::core::option::Option<super::super::v1::SubjectReference>,
+    /// permissionship is the new permissionship of the subject over the resource after the change.
+    #[prost(enumeration = "permission_change::Permissionship", tag = "5")]
+    pub permissionship: i32,
+}
+/// Nested message and enum types in `PermissionChange`.
+pub mod permission_change {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Permissionship {
+        Unspecified = 0,
+        NoPermission = 1,
+        HasPermission = 2,
+        ConditionalPermission = 3,
+    }
+    impl Permissionship {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Permissionship::Unspecified => "PERMISSIONSHIP_UNSPECIFIED",
+                Permissionship::NoPermission => "PERMISSIONSHIP_NO_PERMISSION",
+                Permissionship::HasPermission => "PERMISSIONSHIP_HAS_PERMISSION",
+                Permissionship::ConditionalPermission => {
+                    "PERMISSIONSHIP_CONDITIONAL_PERMISSION"
+                }
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "PERMISSIONSHIP_UNSPECIFIED" => Some(Self::Unspecified),
+                "PERMISSIONSHIP_NO_PERMISSION" => Some(Self::NoPermission),
+                "PERMISSIONSHIP_HAS_PERMISSION" => Some(Self::HasPermission),
+                "PERMISSIONSHIP_CONDITIONAL_PERMISSION" => {
+                    Some(Self::ConditionalPermission)
+                }
+                _ => None,
+            }
+        }
+    }
+}
+/// Generated client implementations.
+pub mod watch_permissions_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct WatchPermissionsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl WatchPermissionsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WatchPermissionsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WatchPermissionsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            WatchPermissionsServiceClient::new(
+                InterceptedService::new(inner, interceptor),
+            )
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// WatchPermissions returns a stream of PermissionChange events for the given permissions.
+        ///
+        /// WatchPermissions is a long-running RPC, and will stream events until the client
+        /// closes the connection or the server terminates the stream. The consumer is responsible of
+        /// keeping track of the last seen revision and resuming the stream from that point in the event
+        /// of disconnection or client-side restarts.
+        ///
+        /// The API does not offer a sharding mechanism and thus there should only be one consumer per target system.
+        /// Implementing an active-active HA consumer setup over the same target system will require coordinating which
+        /// revisions have been consumed in order to prevent transitioning to an inconsistent state.
+        ///
+        /// Usage of WatchPermissions requires to be explicitly enabled on the service, including the permissions to be
+        /// watched. It requires more resources and is less performant than WatchPermissionsSets. It's usage
+        /// is only recommended when performing the set intersections of WatchPermissionSets in the client side is not viable
+        /// or there is a strict application requirement to use consume the computed permissions.
+        pub async fn watch_permissions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchPermissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchPermissionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.materialize.v0.WatchPermissionsService/WatchPermissions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.materialize.v0.WatchPermissionsService",
+                        "WatchPermissions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod watch_permissions_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WatchPermissionsServiceServer.
+    #[async_trait]
+    pub trait WatchPermissionsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the WatchPermissions method.
+        type WatchPermissionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::WatchPermissionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// WatchPermissions returns a stream of PermissionChange events for the given permissions.
+        ///
+        /// WatchPermissions is a long-running RPC, and will stream events until the client
+        /// closes the connection or the server terminates the stream. The consumer is responsible of
+        /// keeping track of the last seen revision and resuming the stream from that point in the event
+        /// of disconnection or client-side restarts.
+        ///
+        /// The API does not offer a sharding mechanism and thus there should only be one consumer per target system.
+        /// Implementing an active-active HA consumer setup over the same target system will require coordinating which
+        /// revisions have been consumed in order to prevent transitioning to an inconsistent state.
+        ///
+        /// Usage of WatchPermissions requires to be explicitly enabled on the service, including the permissions to be
+        /// watched. It requires more resources and is less performant than WatchPermissionsSets. It's usage
+        /// is only recommended when performing the set intersections of WatchPermissionSets in the client side is not viable
+        /// or there is a strict application requirement to use consume the computed permissions.
+        async fn watch_permissions(
+            &self,
+            request: tonic::Request<super::WatchPermissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchPermissionsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct WatchPermissionsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> WatchPermissionsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for WatchPermissionsServiceServer<T>
+    where
+        T: WatchPermissionsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.materialize.v0.WatchPermissionsService/WatchPermissions" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchPermissionsSvc<T: WatchPermissionsService>(pub Arc<T>);
+                    impl<
+                        T: WatchPermissionsService,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchPermissionsRequest,
+                    > for WatchPermissionsSvc<T> {
+                        type Response = super::WatchPermissionsResponse;
+                        type ResponseStream = T::WatchPermissionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchPermissionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WatchPermissionsService>::watch_permissions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchPermissionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for WatchPermissionsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.materialize.v0.WatchPermissionsService";
+    impl<T> tonic::server::NamedService for WatchPermissionsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPermissionSetsRequest {
+    /// optional_starting_after is used to specify the SpiceDB revision to start watching from.
+    /// If not specified, the watch will start from the current SpiceDB revision time of the request ("head revision").
+    #[prost(message, optional, tag = "1")]
+    pub optional_starting_after: ::core::option::Option<super::super::v1::ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPermissionSetsResponse {
+    #[prost(oneof = "watch_permission_sets_response::Response", tags = "1, 2, 3")]
+    pub response: ::core::option::Option<watch_permission_sets_response::Response>,
+}
+/// Nested message and enum types in `WatchPermissionSetsResponse`.
+pub mod watch_permission_sets_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        /// change is the permission set delta that has occurred as result of a mutation in origin SpiceDB.
+        /// The consumer should apply this change to the current state of the permission sets in their target system.
+        /// Once an event arrives with completed_revision instead, the consumer shall consider the set of
+        /// changes originating from that revision completed.
+        ///
+        /// The consumer should keep track of the revision in order to resume streaming in the event of consumer restarts.
+        #[prost(message, tag = "1")]
+        Change(super::PermissionSetChange),
+        /// completed_revision is the revision token that indicates the completion of a set of changes. It may also be
+        /// received without accompanying set of changes, indicating that a mutation in the origin SpiceDB cluster did
+        /// not yield any effective changes in the permission sets
+        #[prost(message, tag = "2")]
+        CompletedRevision(super::super::super::v1::ZedToken),
+        /// lookup_permission_sets_required is a signal that the consumer should perform a LookupPermissionSets call because
+        /// the permission set snapshot needs to be rebuilt from scratch. This typically happens when the origin SpiceDB
+        /// cluster has seen its schema changed.
+        #[prost(message, tag = "3")]
+        LookupPermissionSetsRequired(super::LookupPermissionSetsRequired),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Cursor {
+    /// limit is the number of permission sets to stream over a single LookupPermissionSets call that was requested.
+    #[prost(uint32, tag = "1")]
+    pub limit: u32,
+    /// token is the snapshot revision at which the cursor was computed.
+    #[prost(message, optional, tag = "4")]
+    pub token: ::core::option::Option<super::super::v1::ZedToken>,
+    /// starting_index is an offset of the permission set represented by this cursor
+    #[prost(uint32, tag = "5")]
+    pub starting_index: u32,
+    /// completed_members is a boolean flag that indicates that the cursor has reached the end of the permission sets
+    #[prost(bool, tag = "6")]
+    pub completed_members: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupPermissionSetsRequest {
+    /// limit is the number of permission sets to stream over a single LookupPermissionSets. Once the limit is reached,
+    /// the server will close the stream. If more permission sets are available, the consume should open a new stream
+    /// providing optional_starting_after_cursor, using the cursor from the last response.
+    #[prost(uint32, tag = "1")]
+    pub limit: u32,
+    /// optional_starting_after_cursor is used to specify the offset to start streaming permission sets from.
+    #[prost(message, optional, tag = "4")]
+    pub optional_starting_after_cursor: ::core::option::Option<Cursor>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupPermissionSetsResponse {
+    /// change represents the permission set delta necessary to transition an uninitialized target system to
+    /// a specific snapshot revision. In practice it's not different from the WatchPermissionSetsResponse.change, except
+    /// all changes will be of time SET_OPERATION_ADDED because it's assumed there is no known previous state.
+    ///
+    /// Applying the deltas to a previously initialized target system would yield incorrect results.
+    #[prost(message, optional, tag = "1")]
+    pub change: ::core::option::Option<PermissionSetChange>,
+    /// cursor points to a specific permission set in a revision.
+    /// The consumer should keep track of the cursor in order to resume streaming in the event of consumer restarts. This
+    /// is particularly important in backfill scenarios that may take hours or event days to complete.
+    #[prost(message, optional, tag = "2")]
+    pub cursor: ::core::option::Option<Cursor>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermissionSetChange {
+    /// revision represents the revision at which the permission set change occurred.
+    #[prost(message, optional, tag = "1")]
+    pub at_revision: ::core::option::Option<super::super::v1::ZedToken>,
+    /// operation represents the type of set operation that took place as part of the change
+    #[prost(enumeration = "permission_set_change::SetOperation", tag = "2")]
+    pub operation: i32,
+    /// parent_set represents the permission set parent of either another set or a member
+    #[prost(message, optional, tag = "3")]
+    pub parent_set: ::core::option::Option<SetReference>,
+    #[prost(oneof = "permission_set_change::Child", tags = "4, 5")]
+    pub child: ::core::option::Option<permission_set_change::Child>,
+}
+/// Nested message and enum types in `PermissionSetChange`.
+pub mod permission_set_change {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum SetOperation {
+        Unspecified = 0,
+        Added = 1,
+        Removed = 2,
+    }
+    impl SetOperation {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                SetOperation::Unspecified => "SET_OPERATION_UNSPECIFIED",
+                SetOperation::Added => "SET_OPERATION_ADDED",
+                SetOperation::Removed => "SET_OPERATION_REMOVED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "SET_OPERATION_UNSPECIFIED" => Some(Self::Unspecified),
+                "SET_OPERATION_ADDED" => Some(Self::Added),
+                "SET_OPERATION_REMOVED" => Some(Self::Removed),
+                _ => None,
+            }
+        }
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Child {
+        /// child_set represents the scenario where another set is considered member of the parent set
+        #[prost(message, tag = "4")]
+        ChildSet(super::SetReference),
+        /// child_member represents the scenario where an specific object is considered member of the parent set
+        #[prost(message, tag = "5")]
+        ChildMember(super::MemberReference),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetReference {
+    /// object_type is the type of object in a permission set
+    #[prost(string, tag = "1")]
+    pub object_type: ::prost::alloc::string::String,
+    /// object_id is the ID of a permission set
+    #[prost(string, tag = "2")]
+    pub object_id: ::prost::alloc::string::String,
+    /// permission_or_relation is the permission or relation referenced by this permission set
+    #[prost(string, tag = "3")]
+    pub permission_or_relation: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MemberReference {
+    /// object_type is the type of object of a permission set member
+    #[prost(string, tag = "1")]
+    pub object_type: ::prost::alloc::string::String,
+    /// object_id is the ID of a permission set member
+    #[prost(string, tag = "2")]
+    pub object_id: ::prost::alloc::string::String,
+    /// optional_permission_or_relation is the permission or relation referenced by this permission set member
+    #[prost(string, tag = "3")]
+    pub optional_permission_or_relation: ::prost::alloc::string::String,
+}
+/// LookupPermissionSetsRequired is a signal that the consumer should perform a LookupPermissionSets call because
+/// the permission set snapshot needs to be rebuilt from scratch. This typically happens when the origin SpiceDB
+/// cluster has seen its schema changed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupPermissionSetsRequired {
+    /// required_lookup_at is the snapshot revision at which the permission set needs to be rebuilt to.
+    #[prost(message, optional, tag = "1")]
+    pub required_lookup_at: ::core::option::Option<super::super::v1::ZedToken>,
+}
+/// Generated client implementations.
+pub mod watch_permission_sets_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct WatchPermissionSetsServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl WatchPermissionSetsServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WatchPermissionSetsServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WatchPermissionSetsServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            WatchPermissionSetsServiceClient::new(
+                InterceptedService::new(inner, interceptor),
+            )
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// WatchPermissionSets returns a stream of changes to the sets which can be used to compute the watched permissions.
+        ///
+        /// WatchPermissionSets lets consumers achieve the same thing as WatchPermissions, but trades off a simpler usage model with
+        /// significantly lower computational requirements. Unlike WatchPermissions, this method returns changes to the sets of permissions,
+        /// rather than the individual permissions. Permission sets are a normalized form of the computed permissions, which
+        /// means that the consumer must perform an extra computation over this representation to obtain the final computed
+        /// permissions, typically by intersecting the provided sets.
+        ///
+        /// For example, this would look like a JOIN between the
+        /// materialize permission sets table in a target relation database, the table with the resources to authorize access
+        /// to, and the table with the subject (e.g. a user).
+        ///
+        /// In exchange, the number of changes issued by WatchPermissionSets will be several orders of magnitude less than those
+        /// emitted by WatchPermissions, which has several implications:
+        /// - significantly less resources to compute the sets
+        /// - significantly less messages to stream over the network
+        /// - significantly less events to ingest on the consumer side
+        /// - less ingestion lag from the origin SpiceDB mutation
+        ///
+        /// The type of scenarios WatchPermissionSets is particularly well suited is when a single change
+        /// in the origin SpiceDB can yield millions of changes. For example, in the GitHub authorization model, assigning a role
+        /// to a top-level team of an organization with hundreds of thousands of employees can lead to an explosion of
+        /// permission change events that would require a lot of computational resources to process, both on Materialize and
+        /// the consumer side.
+        ///
+        /// WatchPermissionSets is thus recommended for any larger scale use case where the fan-out in permission changes that
+        /// emerges from a specific schema and data shape is too large to handle effectively.
+        ///
+        /// The API does not offer a sharding mechanism and thus there should only be one consumer per target system.
+        /// Implementing an active-active HA consumer setup over the same target system will require coordinating which
+        /// revisions have been consumed in order to prevent transitioning to an inconsistent state.
+        pub async fn watch_permission_sets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchPermissionSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchPermissionSetsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.materialize.v0.WatchPermissionSetsService/WatchPermissionSets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.materialize.v0.WatchPermissionSetsService",
+                        "WatchPermissionSets",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// LookupPermissionSets returns the current state of the permission sets which can be used to derive the computed permissions.
+        /// It's typically used to backfill the state of the permission sets in the consumer side.
+        ///
+        /// It's a cursored API and the consumer is responsible to keep track of the cursor and use it on each subsequent call.
+        /// Each stream will return <N> permission sets defined by the specified request limit. The server will keep streaming until
+        /// the sets per stream is hit, or the current state of the sets is reached,
+        /// whatever happens first, and then close the stream. The server will indicate there are no more changes to stream
+        /// through the `completed_members` in the cursor.
+        ///
+        /// There may be many elements to stream, and so the consumer should be prepared to resume the stream from the last
+        /// cursor received. Once completed, the consumer may start streaming permission set changes using WatchPermissionSets
+        /// and the revision token from the last LookupPermissionSets response.
+        pub async fn lookup_permission_sets(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LookupPermissionSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::LookupPermissionSetsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.materialize.v0.WatchPermissionSetsService/LookupPermissionSets",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.materialize.v0.WatchPermissionSetsService",
+                        "LookupPermissionSets",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod watch_permission_sets_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WatchPermissionSetsServiceServer.
+    #[async_trait]
+    pub trait WatchPermissionSetsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the WatchPermissionSets method.
+        type WatchPermissionSetsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::WatchPermissionSetsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// WatchPermissionSets returns a stream of changes to the sets which can be used to compute the watched permissions.
+        ///
+        /// WatchPermissionSets lets consumers achieve the same thing as WatchPermissions, but trades off a simpler usage model with
+        /// significantly lower computational requirements. Unlike WatchPermissions, this method returns changes to the sets of permissions,
+        /// rather than the individual permissions. Permission sets are a normalized form of the computed permissions, which
+        /// means that the consumer must perform an extra computation over this representation to obtain the final computed
+        /// permissions, typically by intersecting the provided sets.
+        ///
+        /// For example, this would look like a JOIN between the
+        /// materialize permission sets table in a target relation database, the table with the resources to authorize access
+        /// to, and the table with the subject (e.g. a user).
+        ///
+        /// In exchange, the number of changes issued by WatchPermissionSets will be several orders of magnitude less than those
+        /// emitted by WatchPermissions, which has several implications:
+        /// - significantly less resources to compute the sets
+        /// - significantly less messages to stream over the network
+        /// - significantly less events to ingest on the consumer side
+        /// - less ingestion lag from the origin SpiceDB mutation
+        ///
+        /// The type of scenarios WatchPermissionSets is particularly well suited is when a single change
+        /// in the origin SpiceDB can yield millions of changes. For example, in the GitHub authorization model, assigning a role
+        /// to a top-level team of an organization with hundreds of thousands of employees can lead to an explosion of
+        /// permission change events that would require a lot of computational resources to process, both on Materialize and
+        /// the consumer side.
+        ///
+        /// WatchPermissionSets is thus recommended for any larger scale use case where the fan-out in permission changes that
+        /// emerges from a specific schema and data shape is too large to handle effectively.
+        ///
+        /// The API does not offer a sharding mechanism and thus there should only be one consumer per target system.
+        /// Implementing an active-active HA consumer setup over the same target system will require coordinating which
+        /// revisions have been consumed in order to prevent transitioning to an inconsistent state.
+        async fn watch_permission_sets(
+            &self,
+            request: tonic::Request<super::WatchPermissionSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchPermissionSetsStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LookupPermissionSets method.
+        type LookupPermissionSetsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::LookupPermissionSetsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// LookupPermissionSets returns the current state of the permission sets which can be used to derive the computed permissions.
+        /// It's typically used to backfill the state of the permission sets in the consumer side.
+        ///
+        /// It's a cursored API and the consumer is responsible to keep track of the cursor and use it on each subsequent call.
+        /// Each stream will return <N> permission sets defined by the specified request limit. The server will keep streaming until
+        /// the sets per stream is hit, or the current state of the sets is reached,
+        /// whatever happens first, and then close the stream. The server will indicate there are no more changes to stream
+        /// through the `completed_members` in the cursor.
+        ///
+        /// There may be many elements to stream, and so the consumer should be prepared to resume the stream from the last
+        /// cursor received. Once completed, the consumer may start streaming permission set changes using WatchPermissionSets
+        /// and the revision token from the last LookupPermissionSets response.
+        async fn lookup_permission_sets(
+            &self,
+            request: tonic::Request<super::LookupPermissionSetsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LookupPermissionSetsStream>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct WatchPermissionSetsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> WatchPermissionSetsServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for WatchPermissionSetsServiceServer<T>
+    where
+        T: WatchPermissionSetsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.materialize.v0.WatchPermissionSetsService/WatchPermissionSets" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchPermissionSetsSvc<T: WatchPermissionSetsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: WatchPermissionSetsService,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchPermissionSetsRequest,
+                    > for WatchPermissionSetsSvc<T> {
+                        type Response = super::WatchPermissionSetsResponse;
+                        type ResponseStream = T::WatchPermissionSetsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchPermissionSetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WatchPermissionSetsService>::watch_permission_sets(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchPermissionSetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.materialize.v0.WatchPermissionSetsService/LookupPermissionSets" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupPermissionSetsSvc<T: WatchPermissionSetsService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: WatchPermissionSetsService,
+                    > tonic::server::ServerStreamingService<
+                        super::LookupPermissionSetsRequest,
+                    > for LookupPermissionSetsSvc<T> {
+                        type Response = super::LookupPermissionSetsResponse;
+                        type ResponseStream = T::LookupPermissionSetsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupPermissionSetsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WatchPermissionSetsService>::lookup_permission_sets(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupPermissionSetsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for WatchPermissionSetsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.materialize.v0.WatchPermissionSetsService";
+    impl<T> tonic::server::NamedService for WatchPermissionSetsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
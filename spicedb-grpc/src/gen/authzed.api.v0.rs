@@ -0,0 +1,1033 @@
+// This file is @generated by prost-build.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelationTuple {
+    /// Each tupleset specifies keys of a set of relation tuples. The set can
+    /// include a single tuple key, or all tuples with a given object ID or
+    /// userset in a namespace, optionally constrained by a relation name.
+    ///
+    /// examples:
+    /// doc:readme#viewer@group:eng#member (fully specified)
+    /// doc:*#*#group:eng#member (all tuples that this userset relates to)
+    /// doc:12345#*#* (all tuples with a direct relationship to a document)
+    /// doc:12345#writer#* (all tuples with direct write relationship with the
+    /// document) doc:#writer#group:eng#member (all tuples that eng group has write
+    /// relationship)
+    #[prost(message, optional, tag = "1")]
+    pub object_and_relation: ::core::option::Option<ObjectAndRelation>,
+    #[prost(message, optional, tag = "2")]
+    pub user: ::core::option::Option<User>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectAndRelation {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub object_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub relation: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RelationReference {
+    #[prost(string, tag = "1")]
+    pub namespace: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub relation: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct User {
+    #[prost(oneof = "user::UserOneof", tags = "2")]
+    pub user_oneof: ::core::option::Option<user::UserOneof>,
+}
+/// Nested message and enum types in `User`.
+pub mod user {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum UserOneof {
+        #[prost(message, tag = "2")]
+        Userset(super::ObjectAndRelation),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormatSchemaRequest {
+    #[prost(string, tag = "1")]
+    pub schema: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FormatSchemaResponse {
+    #[prost(message, optional, tag = "1")]
+    pub error: ::core::option::Option<DeveloperError>,
+    #[prost(string, tag = "2")]
+    pub formatted_schema: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeSchemaRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub namespace_configs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpgradeSchemaResponse {
+    #[prost(message, optional, tag = "1")]
+    pub error: ::core::option::Option<DeveloperError>,
+    #[prost(string, tag = "2")]
+    pub upgraded_schema: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareRequest {
+    #[prost(string, tag = "1")]
+    pub schema: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub relationships_yaml: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub validation_yaml: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub assertions_yaml: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShareResponse {
+    #[prost(string, tag = "1")]
+    pub share_reference: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupShareRequest {
+    #[prost(string, tag = "1")]
+    pub share_reference: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LookupShareResponse {
+    #[prost(enumeration = "lookup_share_response::LookupStatus", tag = "1")]
+    pub status: i32,
+    #[prost(string, tag = "2")]
+    pub schema: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub relationships_yaml: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub validation_yaml: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub assertions_yaml: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `LookupShareResponse`.
+pub mod lookup_share_response {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum LookupStatus {
+        UnknownReference = 0,
+        FailedToLookup = 1,
+        ValidReference = 2,
+        UpgradedReference = 3,
+    }
+    impl LookupStatus {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                LookupStatus::UnknownReference => "UNKNOWN_REFERENCE",
+                LookupStatus::FailedToLookup => "FAILED_TO_LOOKUP",
+                LookupStatus::ValidReference => "VALID_REFERENCE",
+                LookupStatus::UpgradedReference => "UPGRADED_REFERENCE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UNKNOWN_REFERENCE" => Some(Self::UnknownReference),
+                "FAILED_TO_LOOKUP" => Some(Self::FailedToLookup),
+                "VALID_REFERENCE" => Some(Self::ValidReference),
+                "UPGRADED_REFERENCE" => Some(Self::UpgradedReference),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RequestContext {
+    #[prost(string, tag = "1")]
+    pub schema: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub relationships: ::prost::alloc::vec::Vec<RelationTuple>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditCheckRequest {
+    #[prost(message, optional, tag = "1")]
+    pub context: ::core::option::Option<RequestContext>,
+    #[prost(message, repeated, tag = "2")]
+    pub check_relationships: ::prost::alloc::vec::Vec<RelationTuple>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditCheckResult {
+    #[prost(message, optional, tag = "1")]
+    pub relationship: ::core::option::Option<RelationTuple>,
+    #[prost(bool, tag = "2")]
+    pub is_member: bool,
+    #[prost(message, optional, tag = "3")]
+    pub error: ::core::option::Option<DeveloperError>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditCheckResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub request_errors: ::prost::alloc::vec::Vec<DeveloperError>,
+    #[prost(message, repeated, tag = "2")]
+    pub check_results: ::prost::alloc::vec::Vec<EditCheckResult>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateRequest {
+    #[prost(message, optional, tag = "1")]
+    pub context: ::core::option::Option<RequestContext>,
+    #[prost(string, tag = "3")]
+    pub validation_yaml: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub update_validation_yaml: bool,
+    #[prost(string, tag = "5")]
+    pub assertions_yaml: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidateResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub request_errors: ::prost::alloc::vec::Vec<DeveloperError>,
+    #[prost(message, repeated, tag = "2")]
+    pub validation_errors: ::prost::alloc::vec::Vec<DeveloperError>,
+    #[prost(string, tag = "3")]
+    pub updated_validation_yaml: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeveloperError {
+    #[prost(string, tag = "1")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub line: u32,
+    #[prost(uint32, tag = "3")]
+    pub column: u32,
+    #[prost(enumeration = "developer_error::Source", tag = "4")]
+    pub source: i32,
+    #[prost(enumeration = "developer_error::ErrorKind", tag = "5")]
+    pub kind: i32,
+    #[prost(string, repeated, tag = "6")]
+    pub path: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// context holds the context for the error. For schema issues, this will be the
+    /// name of the object type. For relationship issues, the full relationship string.
+    #[prost(string, tag = "7")]
+    pub context: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `DeveloperError`.
+pub mod developer_error {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Source {
+        UnknownSource = 0,
+        Schema = 1,
+        Relationship = 2,
+        ValidationYaml = 3,
+        CheckWatch = 4,
+        Assertion = 5,
+    }
+    impl Source {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Source::UnknownSource => "UNKNOWN_SOURCE",
+                Source::Schema => "SCHEMA",
+                Source::Relationship => "RELATIONSHIP",
+                Source::ValidationYaml => "VALIDATION_YAML",
+                Source::CheckWatch => "CHECK_WATCH",
+                Source::Assertion => "ASSERTION",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UNKNOWN_SOURCE" => Some(Self::UnknownSource),
+                "SCHEMA" => Some(Self::Schema),
+                "RELATIONSHIP" => Some(Self::Relationship),
+                "VALIDATION_YAML" => Some(Self::ValidationYaml),
+                "CHECK_WATCH" => Some(Self::CheckWatch),
+                "ASSERTION" => Some(Self::Assertion),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum ErrorKind {
+        UnknownKind = 0,
+        ParseError = 1,
+        SchemaIssue = 2,
+        DuplicateRelationship = 3,
+        MissingExpectedRelationship = 4,
+        ExtraRelationshipFound = 5,
+        UnknownObjectType = 6,
+        UnknownRelation = 7,
+        MaximumRecursion = 8,
+        AssertionFailed = 9,
+    }
+    impl ErrorKind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                ErrorKind::UnknownKind => "UNKNOWN_KIND",
+                ErrorKind::ParseError => "PARSE_ERROR",
+                ErrorKind::SchemaIssue => "SCHEMA_ISSUE",
+                ErrorKind::DuplicateRelationship => "DUPLICATE_RELATIONSHIP",
+                ErrorKind::MissingExpectedRelationship => "MISSING_EXPECTED_RELATIONSHIP",
+                ErrorKind::ExtraRelationshipFound => "EXTRA_RELATIONSHIP_FOUND",
+                ErrorKind::UnknownObjectType => "UNKNOWN_OBJECT_TYPE",
+                ErrorKind::UnknownRelation => "UNKNOWN_RELATION",
+                ErrorKind::MaximumRecursion => "MAXIMUM_RECURSION",
+                ErrorKind::AssertionFailed => "ASSERTION_FAILED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UNKNOWN_KIND" => Some(Self::UnknownKind),
+                "PARSE_ERROR" => Some(Self::ParseError),
+                "SCHEMA_ISSUE" => Some(Self::SchemaIssue),
+                "DUPLICATE_RELATIONSHIP" => Some(Self::DuplicateRelationship),
+                "MISSING_EXPECTED_RELATIONSHIP" => {
+                    Some(Self::MissingExpectedRelationship)
+                }
+                "EXTRA_RELATIONSHIP_FOUND" => Some(Self::ExtraRelationshipFound),
+                "UNKNOWN_OBJECT_TYPE" => Some(Self::UnknownObjectType),
+                "UNKNOWN_RELATION" => Some(Self::UnknownRelation),
+                "MAXIMUM_RECURSION" => Some(Self::MaximumRecursion),
+                "ASSERTION_FAILED" => Some(Self::AssertionFailed),
+                _ => None,
+            }
+        }
+    }
+}
+/// Generated client implementations.
+pub mod developer_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct DeveloperServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl DeveloperServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> DeveloperServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> DeveloperServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            DeveloperServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn edit_check(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EditCheckRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EditCheckResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/EditCheck",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("authzed.api.v0.DeveloperService", "EditCheck"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn validate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidateResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/Validate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("authzed.api.v0.DeveloperService", "Validate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn share(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ShareRequest>,
+        ) -> std::result::Result<tonic::Response<super::ShareResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/Share",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("authzed.api.v0.DeveloperService", "Share"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn lookup_shared(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LookupShareRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupShareResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/LookupShared",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("authzed.api.v0.DeveloperService", "LookupShared"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn upgrade_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpgradeSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpgradeSchemaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/UpgradeSchema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("authzed.api.v0.DeveloperService", "UpgradeSchema"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn format_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FormatSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormatSchemaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v0.DeveloperService/FormatSchema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("authzed.api.v0.DeveloperService", "FormatSchema"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod developer_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with DeveloperServiceServer.
+    #[async_trait]
+    pub trait DeveloperService: std::marker::Send + std::marker::Sync + 'static {
+        async fn edit_check(
+            &self,
+            request: tonic::Request<super::EditCheckRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EditCheckResponse>,
+            tonic::Status,
+        >;
+        async fn validate(
+            &self,
+            request: tonic::Request<super::ValidateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ValidateResponse>,
+            tonic::Status,
+        >;
+        async fn share(
+            &self,
+            request: tonic::Request<super::ShareRequest>,
+        ) -> std::result::Result<tonic::Response<super::ShareResponse>, tonic::Status>;
+        async fn lookup_shared(
+            &self,
+            request: tonic::Request<super::LookupShareRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LookupShareResponse>,
+            tonic::Status,
+        >;
+        async fn upgrade_schema(
+            &self,
+            request: tonic::Request<super::UpgradeSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpgradeSchemaResponse>,
+            tonic::Status,
+        >;
+        async fn format_schema(
+            &self,
+            request: tonic::Request<super::FormatSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FormatSchemaResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct DeveloperServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> DeveloperServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for DeveloperServiceServer<T>
+    where
+        T: DeveloperService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.v0.DeveloperService/EditCheck" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditCheckSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::EditCheckRequest>
+                    for EditCheckSvc<T> {
+                        type Response = super::EditCheckResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditCheckRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::edit_check(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditCheckSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v0.DeveloperService/Validate" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidateSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::ValidateRequest>
+                    for ValidateSvc<T> {
+                        type Response = super::ValidateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::validate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v0.DeveloperService/Share" => {
+                    #[allow(non_camel_case_types)]
+                    struct ShareSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::ShareRequest> for ShareSvc<T> {
+                        type Response = super::ShareResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ShareRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::share(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ShareSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v0.DeveloperService/LookupShared" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupSharedSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::LookupShareRequest>
+                    for LookupSharedSvc<T> {
+                        type Response = super::LookupShareResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupShareRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::lookup_shared(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupSharedSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v0.DeveloperService/UpgradeSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct UpgradeSchemaSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::UpgradeSchemaRequest>
+                    for UpgradeSchemaSvc<T> {
+                        type Response = super::UpgradeSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpgradeSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::upgrade_schema(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UpgradeSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v0.DeveloperService/FormatSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct FormatSchemaSvc<T: DeveloperService>(pub Arc<T>);
+                    impl<
+                        T: DeveloperService,
+                    > tonic::server::UnaryService<super::FormatSchemaRequest>
+                    for FormatSchemaSvc<T> {
+                        type Response = super::FormatSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FormatSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as DeveloperService>::format_schema(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FormatSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for DeveloperServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.v0.DeveloperService";
+    impl<T> tonic::server::NamedService for DeveloperServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
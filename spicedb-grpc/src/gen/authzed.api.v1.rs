@@ -1,4 +1,501 @@
 // This file is @generated by prost-build.
+/// Defines the supported values for `google.rpc.ErrorInfo.reason` for the
+/// `authzed.com` error domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ErrorReason {
+    /// Do not use this default value.
+    Unspecified = 0,
+    /// The request gave a schema that could not be parsed.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_SCHEMA_PARSE_ERROR",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "start_line_number": "1",
+    ///          "start_column_position": "19",
+    ///          "end_line_number": "1",
+    ///          "end_column_position": "19",
+    ///          "source_code": "somedefinition",
+    ///        }
+    ///      }
+    ///
+    /// The line numbers and column positions are 0-indexed and may not be present.
+    SchemaParseError = 1,
+    /// The request contains a schema with a type error.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_SCHEMA_TYPE_ERROR",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          ... additional keys based on the kind of type error ...
+    ///        }
+    ///      }
+    SchemaTypeError = 2,
+    /// The request referenced an unknown object definition in the schema.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_UNKNOWN_DEFINITION",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition"
+    ///        }
+    ///      }
+    UnknownDefinition = 3,
+    /// The request referenced an unknown relation or permission under a definition in the schema.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_UNKNOWN_RELATION_OR_PERMISSION",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          "relation_or_permission_name": "somepermission"
+    ///        }
+    ///      }
+    UnknownRelationOrPermission = 4,
+    /// The WriteRelationships request contained more updates than the maximum configured.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      { "reason": "ERROR_REASON_TOO_MANY_UPDATES_IN_REQUEST",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "update_count": "525",
+    ///          "maximum_updates_allowed": "500",
+    ///        }
+    ///      }
+    TooManyUpdatesInRequest = 5,
+    /// The request contained more preconditions than the maximum configured.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_TOO_MANY_PRECONDITIONS_IN_REQUEST",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "precondition_count": "525",
+    ///          "maximum_preconditions_allowed": "500",
+    ///        }
+    ///      }
+    TooManyPreconditionsInRequest = 6,
+    /// The request contained a precondition that failed.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_WRITE_OR_DELETE_PRECONDITION_FAILURE",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "precondition_resource_type": "document",
+    ///          ... other fields for the filter ...
+    ///          "precondition_operation": "MUST_EXIST",
+    ///        }
+    ///      }
+    WriteOrDeletePreconditionFailure = 7,
+    /// A write or delete request was made to an instance that is deployed in read-only mode.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_SERVICE_READ_ONLY",
+    ///        "domain": "authzed.com"
+    ///      }
+    ServiceReadOnly = 8,
+    /// The request referenced an unknown caveat in the schema.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_UNKNOWN_CAVEAT",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "caveat_name": "somecaveat"
+    ///        }
+    ///      }
+    UnknownCaveat = 9,
+    /// The request tries to use a subject type that was not valid for a relation.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_INVALID_SUBJECT_TYPE",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          "relation_name": "somerelation",
+    ///          "subject_type": "user:*"
+    ///        }
+    ///      }
+    InvalidSubjectType = 10,
+    /// The request tries to specify a caveat parameter value with the wrong type.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_CAVEAT_PARAMETER_TYPE_ERROR",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          "relation_name": "somerelation",
+    ///          "caveat_name": "somecaveat",
+    ///          "parameter_name": "someparameter",
+    ///          "expected_type": "int",
+    ///        }
+    ///      }
+    CaveatParameterTypeError = 11,
+    /// The request tries to perform two or more updates on the same relationship in the same WriteRelationships call.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_UPDATES_ON_SAME_RELATIONSHIP",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          "relationship": "somerelationship",
+    ///        }
+    ///      }
+    UpdatesOnSameRelationship = 12,
+    /// The request tries to write a relationship on a permission instead of a relation.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_CANNOT_UPDATE_PERMISSION",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "definition_name": "somedefinition",
+    ///          "permission_name": "somerelation",
+    ///        }
+    ///      }
+    CannotUpdatePermission = 13,
+    /// The request failed to evaluate a caveat expression due to an error.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_CAVEAT_EVALUATION_ERROR",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "caveat_name": "somecaveat",
+    ///        }
+    ///      }
+    CaveatEvaluationError = 14,
+    /// The request failed because the provided cursor was invalid in some way.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_INVALID_CURSOR",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///           ... additional keys based on the kind of cursor error ...
+    ///        }
+    ///      }
+    InvalidCursor = 15,
+    /// The request failed because there are too many matching relationships to be
+    /// deleted within a single transactional deletion call. To avoid, set
+    /// `optional_allow_partial_deletions` to true on the DeleteRelationships call.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_TOO_MANY_RELATIONSHIPS_FOR_TRANSACTIONAL_DELETE",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///           ... fields for the filter ...
+    ///        }
+    ///      }
+    TooManyRelationshipsForTransactionalDelete = 16,
+    /// The request failed because the client attempted to write a relationship
+    /// with a context that exceeded the configured server limit.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_MAX_RELATIONSHIP_CONTEXT_SIZE",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "relationship":     "relationship_exceeding_the_limit",
+    ///          "max_allowed_size": "server_max_allowed_context_size",
+    ///          "context_size":     "actual_relationship_context_size" ,
+    ///        }
+    ///      }
+    MaxRelationshipContextSize = 17,
+    /// The request failed because a relationship marked to be CREATEd
+    /// was already present within the datastore.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_ATTEMPT_TO_RECREATE_RELATIONSHIP",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "relationship":          "relationship_that_already_existed",
+    ///          "resource_type":         "resource type",
+    ///          "resource_object_id":    "resource object id",
+    ///          ... additional decomposed relationship fields ...
+    ///        }
+    ///      }
+    AttemptToRecreateRelationship = 18,
+    /// The request failed because it caused the maximum depth allowed to be
+    /// exceeded. This typically indicates that there is a circular data traversal
+    /// somewhere in the schema, but can also be raised if the data traversal is simply
+    /// too deep.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_MAXIMUM_DEPTH_EXCEEDED",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "maximum_depth_allowed": "50",
+    ///          ... additional fields based on request type ...
+    ///        }
+    ///      }
+    MaximumDepthExceeded = 19,
+    /// The request failed due to a serialization error in the backend database.
+    /// This typically indicates that various in flight transactions conflicted with each other
+    /// and the database had to abort one or more of them. SpiceDB will retry a few times before returning
+    /// the error to the client.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_SERIALIZATION_FAILURE",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {}
+    ///      }
+    SerializationFailure = 20,
+    /// The request contained more check items than the maximum configured.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_TOO_MANY_CHECKS_IN_REQUEST",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "check_count": "525",
+    ///          "maximum_checks_allowed": "500",
+    ///        }
+    ///      }
+    TooManyChecksInRequest = 21,
+    /// The request's specified limit is too large.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_EXCEEDS_MAXIMUM_ALLOWABLE_LIMIT",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "limit_provided": "525",
+    ///          "maximum_limit_allowed": "500",
+    ///        }
+    ///      }
+    ExceedsMaximumAllowableLimit = 22,
+    /// The request failed because the provided filter was invalid in some way.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_INVALID_FILTER",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {
+    ///          "filter": "...",
+    ///        }
+    ///      }
+    InvalidFilter = 23,
+    /// The request failed because too many concurrent updates were attempted
+    /// against the in-memory datastore.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_INMEMORY_TOO_MANY_CONCURRENT_UPDATES",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {}
+    ///      }
+    InmemoryTooManyConcurrentUpdates = 24,
+    /// The request failed because the precondition specified is empty.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_EMPTY_PRECONDITION",
+    ///        "domain": "authzed.com",
+    ///        "metadata": {}
+    ///      }
+    EmptyPrecondition = 25,
+    /// The request failed because the counter was already registered.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_COUNTER_ALREADY_REGISTERED",
+    ///        "domain": "authzed.com",
+    ///        "metadata": { "counter_name": "name" }
+    ///      }
+    CounterAlreadyRegistered = 26,
+    /// The request failed because the counter was not registered.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_COUNTER_NOT_REGISTERED",
+    ///        "domain": "authzed.com",
+    ///        "metadata": { "counter_name": "name"  }
+    ///      }
+    CounterNotRegistered = 27,
+    /// The request failed because a wildcard was not allowed. For CheckPermission,
+    /// this means that the subject or resource ID was a wildcard. For LookupResources,
+    /// this means that the subject ID was a wildcard.
+    ///
+    /// Example of an ErrorInfo:
+    ///
+    ///      {
+    ///        "reason": "ERROR_REASON_WILDCARD_NOT_ALLOWED",
+    ///        "domain": "authzed.com",
+    ///        "metadata": { "disallowed_field": "subject_id" }
+    ///      }
+    WildcardNotAllowed = 28,
+}
+impl ErrorReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ErrorReason::Unspecified => "ERROR_REASON_UNSPECIFIED",
+            ErrorReason::SchemaParseError => "ERROR_REASON_SCHEMA_PARSE_ERROR",
+            ErrorReason::SchemaTypeError => "ERROR_REASON_SCHEMA_TYPE_ERROR",
+            ErrorReason::UnknownDefinition => "ERROR_REASON_UNKNOWN_DEFINITION",
+            ErrorReason::UnknownRelationOrPermission => {
+                "ERROR_REASON_UNKNOWN_RELATION_OR_PERMISSION"
+            }
+            ErrorReason::TooManyUpdatesInRequest => {
+                "ERROR_REASON_TOO_MANY_UPDATES_IN_REQUEST"
+            }
+            ErrorReason::TooManyPreconditionsInRequest => {
+                "ERROR_REASON_TOO_MANY_PRECONDITIONS_IN_REQUEST"
+            }
+            ErrorReason::WriteOrDeletePreconditionFailure => {
+                "ERROR_REASON_WRITE_OR_DELETE_PRECONDITION_FAILURE"
+            }
+            ErrorReason::ServiceReadOnly => "ERROR_REASON_SERVICE_READ_ONLY",
+            ErrorReason::UnknownCaveat => "ERROR_REASON_UNKNOWN_CAVEAT",
+            ErrorReason::InvalidSubjectType => "ERROR_REASON_INVALID_SUBJECT_TYPE",
+            ErrorReason::CaveatParameterTypeError => {
+                "ERROR_REASON_CAVEAT_PARAMETER_TYPE_ERROR"
+            }
+            ErrorReason::UpdatesOnSameRelationship => {
+                "ERROR_REASON_UPDATES_ON_SAME_RELATIONSHIP"
+            }
+            ErrorReason::CannotUpdatePermission => {
+                "ERROR_REASON_CANNOT_UPDATE_PERMISSION"
+            }
+            ErrorReason::CaveatEvaluationError => "ERROR_REASON_CAVEAT_EVALUATION_ERROR",
+            ErrorReason::InvalidCursor => "ERROR_REASON_INVALID_CURSOR",
+            ErrorReason::TooManyRelationshipsForTransactionalDelete => {
+                "ERROR_REASON_TOO_MANY_RELATIONSHIPS_FOR_TRANSACTIONAL_DELETE"
+            }
+            ErrorReason::MaxRelationshipContextSize => {
+                "ERROR_REASON_MAX_RELATIONSHIP_CONTEXT_SIZE"
+            }
+            ErrorReason::AttemptToRecreateRelationship => {
+                "ERROR_REASON_ATTEMPT_TO_RECREATE_RELATIONSHIP"
+            }
+            ErrorReason::MaximumDepthExceeded => "ERROR_REASON_MAXIMUM_DEPTH_EXCEEDED",
+            ErrorReason::SerializationFailure => "ERROR_REASON_SERIALIZATION_FAILURE",
+            ErrorReason::TooManyChecksInRequest => {
+                "ERROR_REASON_TOO_MANY_CHECKS_IN_REQUEST"
+            }
+            ErrorReason::ExceedsMaximumAllowableLimit => {
+                "ERROR_REASON_EXCEEDS_MAXIMUM_ALLOWABLE_LIMIT"
+            }
+            ErrorReason::InvalidFilter => "ERROR_REASON_INVALID_FILTER",
+            ErrorReason::InmemoryTooManyConcurrentUpdates => {
+                "ERROR_REASON_INMEMORY_TOO_MANY_CONCURRENT_UPDATES"
+            }
+            ErrorReason::EmptyPrecondition => "ERROR_REASON_EMPTY_PRECONDITION",
+            ErrorReason::CounterAlreadyRegistered => {
+                "ERROR_REASON_COUNTER_ALREADY_REGISTERED"
+            }
+            ErrorReason::CounterNotRegistered => "ERROR_REASON_COUNTER_NOT_REGISTERED",
+            ErrorReason::WildcardNotAllowed => "ERROR_REASON_WILDCARD_NOT_ALLOWED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ERROR_REASON_UNSPECIFIED" => Some(Self::Unspecified),
+            "ERROR_REASON_SCHEMA_PARSE_ERROR" => Some(Self::SchemaParseError),
+            "ERROR_REASON_SCHEMA_TYPE_ERROR" => Some(Self::SchemaTypeError),
+            "ERROR_REASON_UNKNOWN_DEFINITION" => Some(Self::UnknownDefinition),
+            "ERROR_REASON_UNKNOWN_RELATION_OR_PERMISSION" => {
+                Some(Self::UnknownRelationOrPermission)
+            }
+            "ERROR_REASON_TOO_MANY_UPDATES_IN_REQUEST" => {
+                Some(Self::TooManyUpdatesInRequest)
+            }
+            "ERROR_REASON_TOO_MANY_PRECONDITIONS_IN_REQUEST" => {
+                Some(Self::TooManyPreconditionsInRequest)
+            }
+            "ERROR_REASON_WRITE_OR_DELETE_PRECONDITION_FAILURE" => {
+                Some(Self::WriteOrDeletePreconditionFailure)
+            }
+            "ERROR_REASON_SERVICE_READ_ONLY" => Some(Self::ServiceReadOnly),
+            "ERROR_REASON_UNKNOWN_CAVEAT" => Some(Self::UnknownCaveat),
+            "ERROR_REASON_INVALID_SUBJECT_TYPE" => Some(Self::InvalidSubjectType),
+            "ERROR_REASON_CAVEAT_PARAMETER_TYPE_ERROR" => {
+                Some(Self::CaveatParameterTypeError)
+            }
+            "ERROR_REASON_UPDATES_ON_SAME_RELATIONSHIP" => {
+                Some(Self::UpdatesOnSameRelationship)
+            }
+            "ERROR_REASON_CANNOT_UPDATE_PERMISSION" => Some(Self::CannotUpdatePermission),
+            "ERROR_REASON_CAVEAT_EVALUATION_ERROR" => Some(Self::CaveatEvaluationError),
+            "ERROR_REASON_INVALID_CURSOR" => Some(Self::InvalidCursor),
+            "ERROR_REASON_TOO_MANY_RELATIONSHIPS_FOR_TRANSACTIONAL_DELETE" => {
+                Some(Self::TooManyRelationshipsForTransactionalDelete)
+            }
+            "ERROR_REASON_MAX_RELATIONSHIP_CONTEXT_SIZE" => {
+                Some(Self::MaxRelationshipContextSize)
+            }
+            "ERROR_REASON_ATTEMPT_TO_RECREATE_RELATIONSHIP" => {
+                Some(Self::AttemptToRecreateRelationship)
+            }
+            "ERROR_REASON_MAXIMUM_DEPTH_EXCEEDED" => Some(Self::MaximumDepthExceeded),
+            "ERROR_REASON_SERIALIZATION_FAILURE" => Some(Self::SerializationFailure),
+            "ERROR_REASON_TOO_MANY_CHECKS_IN_REQUEST" => {
+                Some(Self::TooManyChecksInRequest)
+            }
+            "ERROR_REASON_EXCEEDS_MAXIMUM_ALLOWABLE_LIMIT" => {
+                Some(Self::ExceedsMaximumAllowableLimit)
+            }
+            "ERROR_REASON_INVALID_FILTER" => Some(Self::InvalidFilter),
+            "ERROR_REASON_INMEMORY_TOO_MANY_CONCURRENT_UPDATES" => {
+                Some(Self::InmemoryTooManyConcurrentUpdates)
+            }
+            "ERROR_REASON_EMPTY_PRECONDITION" => Some(Self::EmptyPrecondition),
+            "ERROR_REASON_COUNTER_ALREADY_REGISTERED" => {
+                Some(Self::CounterAlreadyRegistered)
+            }
+            "ERROR_REASON_COUNTER_NOT_REGISTERED" => Some(Self::CounterNotRegistered),
+            "ERROR_REASON_WILDCARD_NOT_ALLOWED" => Some(Self::WildcardNotAllowed),
+            _ => None,
+        }
+    }
+}
 /// Relationship specifies how a resource relates to a subject. Relationships
 /// form the data for the graph over which all permissions questions are
 /// answered.
@@ -1188,7 +1685,13 @@ impl LookupPermissionship {
 }
 /// Generated client implementations.
 pub mod permissions_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     /// PermissionsService implements a set of RPCs that perform operations on
@@ -1212,8 +1715,8 @@ pub mod permissions_service_client {
     where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
     {
         pub fn new(inner: T) -> Self {
             let inner = tonic::client::Grpc::new(inner);
@@ -1238,7 +1741,7 @@ pub mod permissions_service_client {
             >,
             <T as tonic::codegen::Service<
                 http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
             PermissionsServiceClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -1286,8 +1789,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1319,8 +1821,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1353,8 +1854,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1385,8 +1885,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1417,8 +1916,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1450,8 +1948,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1482,8 +1979,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1514,8 +2010,7 @@ pub mod permissions_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1535,111 +2030,160 @@ pub mod permissions_service_client {
         }
     }
 }
-/// ReadSchemaRequest returns the schema from the database.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ReadSchemaRequest {}
-/// ReadSchemaResponse is the resulting data after having read the Object
-/// Definitions from a Schema.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ReadSchemaResponse {
-    /// schema_text is the textual form of the current schema in the system
-    #[prost(string, tag = "1")]
-    pub schema_text: ::prost::alloc::string::String,
-    /// read_at is the ZedToken at which the schema was read.
-    #[prost(message, optional, tag = "2")]
-    pub read_at: ::core::option::Option<ZedToken>,
-}
-/// WriteSchemaRequest is the required data used to "upsert" the Schema of a
-/// Permissions System.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WriteSchemaRequest {
-    /// The Schema containing one or more Object Definitions that will be written
-    /// to the Permissions System.
-    ///
-    /// 4MiB
-    #[prost(string, tag = "1")]
-    pub schema: ::prost::alloc::string::String,
-}
-/// WriteSchemaResponse is the resulting data after having written a Schema to
-/// a Permissions System.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct WriteSchemaResponse {
-    /// written_at is the ZedToken at which the schema was written.
-    #[prost(message, optional, tag = "1")]
-    pub written_at: ::core::option::Option<ZedToken>,
-}
-/// Generated client implementations.
-pub mod schema_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod permissions_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
     use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// SchemaService implements operations on a Permissions System's Schema.
-    #[derive(Debug, Clone)]
-    pub struct SchemaServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
+    /// Generated trait containing gRPC methods that should be implemented for use with PermissionsServiceServer.
+    #[async_trait]
+    pub trait PermissionsService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the ReadRelationships method.
+        type ReadRelationshipsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::ReadRelationshipsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// ReadRelationships reads a set of the relationships matching one or more
+        /// filters.
+        async fn read_relationships(
+            &self,
+            request: tonic::Request<super::ReadRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ReadRelationshipsStream>,
+            tonic::Status,
+        >;
+        /// WriteRelationships atomically writes and/or deletes a set of specified
+        /// relationships. An optional set of preconditions can be provided that must
+        /// be satisfied for the operation to commit.
+        async fn write_relationships(
+            &self,
+            request: tonic::Request<super::WriteRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WriteRelationshipsResponse>,
+            tonic::Status,
+        >;
+        /// DeleteRelationships atomically bulk deletes all relationships matching the
+        /// provided filter. If no relationships match, none will be deleted and the
+        /// operation will succeed. An optional set of preconditions can be provided that must
+        /// be satisfied for the operation to commit.
+        async fn delete_relationships(
+            &self,
+            request: tonic::Request<super::DeleteRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteRelationshipsResponse>,
+            tonic::Status,
+        >;
+        /// CheckPermission determines for a given resource whether a subject computes
+        /// to having a permission or is a direct member of a particular relation.
+        async fn check_permission(
+            &self,
+            request: tonic::Request<super::CheckPermissionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckPermissionResponse>,
+            tonic::Status,
+        >;
+        /// CheckBulkPermissions evaluates the given list of permission checks
+        /// and returns the list of results.
+        async fn check_bulk_permissions(
+            &self,
+            request: tonic::Request<super::CheckBulkPermissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CheckBulkPermissionsResponse>,
+            tonic::Status,
+        >;
+        /// ExpandPermissionTree reveals the graph structure for a resource's
+        /// permission or relation. This RPC does not recurse infinitely deep and may
+        /// require multiple calls to fully unnest a deeply nested graph.
+        async fn expand_permission_tree(
+            &self,
+            request: tonic::Request<super::ExpandPermissionTreeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExpandPermissionTreeResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LookupResources method.
+        type LookupResourcesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::LookupResourcesResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// LookupResources returns all the resources of a given type that a subject
+        /// can access whether via a computed permission or relation membership.
+        async fn lookup_resources(
+            &self,
+            request: tonic::Request<super::LookupResourcesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LookupResourcesStream>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LookupSubjects method.
+        type LookupSubjectsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::LookupSubjectsResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// LookupSubjects returns all the subjects of a given type that
+        /// have access whether via a computed permission or relation membership.
+        async fn lookup_subjects(
+            &self,
+            request: tonic::Request<super::LookupSubjectsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LookupSubjectsStream>,
+            tonic::Status,
+        >;
     }
-    impl SchemaServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
+    /// PermissionsService implements a set of RPCs that perform operations on
+    /// relationships and permissions.
+    #[derive(Debug)]
+    pub struct PermissionsServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
     }
-    impl<T> SchemaServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::BoxBody>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
-    {
+    impl<T> PermissionsServiceServer<T> {
         pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
+            Self::from_arc(Arc::new(inner))
         }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
         }
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> SchemaServiceClient<InterceptedService<T, F>>
+        ) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
         {
-            SchemaServiceClient::new(InterceptedService::new(inner, interceptor))
+            InterceptedService::new(Self::new(inner), interceptor)
         }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
+        /// Enable decompressing requests with the given encoding.
         #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
             self
         }
-        /// Enable decompressing responses.
+        /// Compress responses with the given encoding, if the client supports it.
         #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
             self
         }
         /// Limits the maximum size of a decoded message.
@@ -1647,7 +2191,7 @@ pub mod schema_service_client {
         /// Default: `4MB`
         #[must_use]
         pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
+            self.max_decoding_message_size = Some(limit);
             self
         }
         /// Limits the maximum size of an encoded message.
@@ -1655,27 +2199,2266 @@ pub mod schema_service_client {
         /// Default: `usize::MAX`
         #[must_use]
         pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
+            self.max_encoding_message_size = Some(limit);
             self
         }
-        /// Read returns the current Object Definitions for a Permissions System.
-        ///
-        /// Errors include:
-        /// - INVALID_ARGUMENT: a provided value has failed to semantically validate
-        /// - NOT_FOUND: no schema has been defined
-        pub async fn read_schema(
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PermissionsServiceServer<T>
+    where
+        T: PermissionsService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
             &mut self,
-            request: impl tonic::IntoRequest<super::ReadSchemaRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ReadSchemaResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.v1.PermissionsService/ReadRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadRelationshipsSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::ServerStreamingService<
+                        super::ReadRelationshipsRequest,
+                    > for ReadRelationshipsSvc<T> {
+                        type Response = super::ReadRelationshipsResponse;
+                        type ResponseStream = T::ReadRelationshipsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadRelationshipsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::read_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/WriteRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct WriteRelationshipsSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::UnaryService<super::WriteRelationshipsRequest>
+                    for WriteRelationshipsSvc<T> {
+                        type Response = super::WriteRelationshipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WriteRelationshipsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::write_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WriteRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/DeleteRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteRelationshipsSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::UnaryService<super::DeleteRelationshipsRequest>
+                    for DeleteRelationshipsSvc<T> {
+                        type Response = super::DeleteRelationshipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteRelationshipsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::delete_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/CheckPermission" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckPermissionSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::UnaryService<super::CheckPermissionRequest>
+                    for CheckPermissionSvc<T> {
+                        type Response = super::CheckPermissionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckPermissionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::check_permission(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckPermissionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/CheckBulkPermissions" => {
+                    #[allow(non_camel_case_types)]
+                    struct CheckBulkPermissionsSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::UnaryService<super::CheckBulkPermissionsRequest>
+                    for CheckBulkPermissionsSvc<T> {
+                        type Response = super::CheckBulkPermissionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CheckBulkPermissionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::check_bulk_permissions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CheckBulkPermissionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/ExpandPermissionTree" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExpandPermissionTreeSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::UnaryService<super::ExpandPermissionTreeRequest>
+                    for ExpandPermissionTreeSvc<T> {
+                        type Response = super::ExpandPermissionTreeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExpandPermissionTreeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::expand_permission_tree(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExpandPermissionTreeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/LookupResources" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupResourcesSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::ServerStreamingService<
+                        super::LookupResourcesRequest,
+                    > for LookupResourcesSvc<T> {
+                        type Response = super::LookupResourcesResponse;
+                        type ResponseStream = T::LookupResourcesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupResourcesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::lookup_resources(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupResourcesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.PermissionsService/LookupSubjects" => {
+                    #[allow(non_camel_case_types)]
+                    struct LookupSubjectsSvc<T: PermissionsService>(pub Arc<T>);
+                    impl<
+                        T: PermissionsService,
+                    > tonic::server::ServerStreamingService<super::LookupSubjectsRequest>
+                    for LookupSubjectsSvc<T> {
+                        type Response = super::LookupSubjectsResponse;
+                        type ResponseStream = T::LookupSubjectsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LookupSubjectsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PermissionsService>::lookup_subjects(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LookupSubjectsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for PermissionsServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.v1.PermissionsService";
+    impl<T> tonic::server::NamedService for PermissionsServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalRegisterRelationshipCounterRequest {
+    /// name is the name of the counter being registered.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// relationship_filter defines the filter to be applied to the relationships
+    /// to be counted.
+    #[prost(message, optional, tag = "2")]
+    pub relationship_filter: ::core::option::Option<RelationshipFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExperimentalRegisterRelationshipCounterResponse {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalCountRelationshipsRequest {
+    /// name is the name of the counter whose count is being requested.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalCountRelationshipsResponse {
+    #[prost(
+        oneof = "experimental_count_relationships_response::CounterResult",
+        tags = "1, 2"
+    )]
+    pub counter_result: ::core::option::Option<
+        experimental_count_relationships_response::CounterResult,
+    >,
+}
+/// Nested message and enum types in `ExperimentalCountRelationshipsResponse`.
+pub mod experimental_count_relationships_response {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum CounterResult {
+        /// counter_still_calculating is true if the counter is still calculating the count.
+        #[prost(bool, tag = "1")]
+        CounterStillCalculating(bool),
+        /// read_counter_value is the value of the counter at the time of the read.
+        #[prost(message, tag = "2")]
+        ReadCounterValue(super::ReadCounterValue),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadCounterValue {
+    /// relationship_count is the count of relationships that match the filter.
+    #[prost(uint64, tag = "1")]
+    pub relationship_count: u64,
+    /// read_at is the ZedToken at which the relationship count applies.
+    #[prost(message, optional, tag = "2")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalUnregisterRelationshipCounterRequest {
+    /// name is the name of the counter being unregistered.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExperimentalUnregisterRelationshipCounterResponse {}
+/// NOTE: Deprecated now that BulkCheckPermission has been promoted to the stable API as "CheckBulkPermission".
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkCheckPermissionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    #[deprecated]
+    #[prost(message, repeated, tag = "2")]
+    pub items: ::prost::alloc::vec::Vec<BulkCheckPermissionRequestItem>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkCheckPermissionRequestItem {
+    #[prost(message, optional, tag = "1")]
+    pub resource: ::core::option::Option<ObjectReference>,
+    #[prost(string, tag = "2")]
+    pub permission: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub subject: ::core::option::Option<SubjectReference>,
+    #[prost(message, optional, tag = "4")]
+    pub context: ::core::option::Option<::prost_types::Struct>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkCheckPermissionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub checked_at: ::core::option::Option<ZedToken>,
+    #[prost(message, repeated, tag = "2")]
+    pub pairs: ::prost::alloc::vec::Vec<BulkCheckPermissionPair>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkCheckPermissionPair {
+    #[prost(message, optional, tag = "1")]
+    pub request: ::core::option::Option<BulkCheckPermissionRequestItem>,
+    #[prost(oneof = "bulk_check_permission_pair::Response", tags = "2, 3")]
+    pub response: ::core::option::Option<bulk_check_permission_pair::Response>,
+}
+/// Nested message and enum types in `BulkCheckPermissionPair`.
+pub mod bulk_check_permission_pair {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Response {
+        #[prost(message, tag = "2")]
+        Item(super::BulkCheckPermissionResponseItem),
+        #[prost(message, tag = "3")]
+        Error(super::super::super::super::google::rpc::Status),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkCheckPermissionResponseItem {
+    #[prost(enumeration = "check_permission_response::Permissionship", tag = "1")]
+    pub permissionship: i32,
+    #[prost(message, optional, tag = "2")]
+    pub partial_caveat_info: ::core::option::Option<PartialCaveatInfo>,
+}
+/// BulkImportRelationshipsRequest represents one batch of the streaming
+/// BulkImportRelationships API. The maximum size is only limited by the backing
+/// datastore, and optimal size should be determined by the calling client
+/// experimentally.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkImportRelationshipsRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub relationships: ::prost::alloc::vec::Vec<Relationship>,
+}
+/// BulkImportRelationshipsResponse is returned on successful completion of the
+/// bulk load stream, and contains the total number of relationships loaded.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct BulkImportRelationshipsResponse {
+    #[prost(uint64, tag = "1")]
+    pub num_loaded: u64,
+}
+/// BulkExportRelationshipsRequest represents a resumable request for
+/// all relationships from the server.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkExportRelationshipsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    /// optional_limit, if non-zero, specifies the limit on the number of
+    /// relationships the server can return in one page. By default, the server
+    /// will pick a page size, and the server is free to choose a smaller size
+    /// at will.
+    #[prost(uint32, tag = "2")]
+    pub optional_limit: u32,
+    /// optional_cursor, if specified, indicates the cursor after which results
+    /// should resume being returned. The cursor can be found on the
+    /// BulkExportRelationshipsResponse object.
+    #[prost(message, optional, tag = "3")]
+    pub optional_cursor: ::core::option::Option<Cursor>,
+    /// optional_relationship_filter, if specified, indicates the
+    /// filter to apply to each relationship to be exported.
+    #[prost(message, optional, tag = "4")]
+    pub optional_relationship_filter: ::core::option::Option<RelationshipFilter>,
+}
+/// BulkExportRelationshipsResponse is one page in a stream of relationship
+/// groups that meet the criteria specified by the originating request. The
+/// server will continue to stream back relationship groups as quickly as it can
+/// until all relationships have been transmitted back.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkExportRelationshipsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub after_result_cursor: ::core::option::Option<Cursor>,
+    #[prost(message, repeated, tag = "2")]
+    pub relationships: ::prost::alloc::vec::Vec<Relationship>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalReflectSchemaRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    /// optional_filters defines optional filters that are applied in
+    /// an OR fashion to the schema, before being returned
+    #[prost(message, repeated, tag = "2")]
+    pub optional_filters: ::prost::alloc::vec::Vec<ExpSchemaFilter>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalReflectSchemaResponse {
+    /// definitions are the definitions defined in the schema.
+    #[prost(message, repeated, tag = "1")]
+    pub definitions: ::prost::alloc::vec::Vec<ExpDefinition>,
+    /// caveats are the caveats defined in the schema.
+    #[prost(message, repeated, tag = "2")]
+    pub caveats: ::prost::alloc::vec::Vec<ExpCaveat>,
+    /// read_at is the ZedToken at which the schema was read.
+    #[prost(message, optional, tag = "3")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+/// ExpSchemaFilter is a filter that can be applied to the schema on reflection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpSchemaFilter {
+    /// optional_definition_name_filter is a prefix that is matched against the definition name.
+    #[prost(string, tag = "1")]
+    pub optional_definition_name_filter: ::prost::alloc::string::String,
+    /// optional_caveat_name_filter is a prefix that is matched against the caveat name.
+    #[prost(string, tag = "2")]
+    pub optional_caveat_name_filter: ::prost::alloc::string::String,
+    /// optional_relation_name_filter is a prefix that is matched against the relation name.
+    #[prost(string, tag = "3")]
+    pub optional_relation_name_filter: ::prost::alloc::string::String,
+    /// optional_permission_name_filter is a prefix that is matched against the permission name.
+    #[prost(string, tag = "4")]
+    pub optional_permission_name_filter: ::prost::alloc::string::String,
+}
+/// ExpDefinition is the representation of a definition in the schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpDefinition {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// comment is a human-readable comments on the definition. Will include
+    /// delimiter characters.
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub relations: ::prost::alloc::vec::Vec<ExpRelation>,
+    #[prost(message, repeated, tag = "4")]
+    pub permissions: ::prost::alloc::vec::Vec<ExpPermission>,
+}
+/// ExpCaveat is the representation of a caveat in the schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpCaveat {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// comment is a human-readable comments on the caveat. Will include
+    /// delimiter characters.
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub parameters: ::prost::alloc::vec::Vec<ExpCaveatParameter>,
+    #[prost(string, tag = "4")]
+    pub expression: ::prost::alloc::string::String,
+}
+/// ExpCaveatParameter is the representation of a parameter in a caveat.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpCaveatParameter {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// type is the type of the parameter. Will be a string representing the
+    /// type, e.g. `int` or `list<string>`
+    #[prost(string, tag = "2")]
+    pub r#type: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub parent_caveat_name: ::prost::alloc::string::String,
+}
+/// ExpRelation is the representation of a relation in the schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpRelation {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub parent_definition_name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub subject_types: ::prost::alloc::vec::Vec<ExpTypeReference>,
+}
+/// ExpTypeReference is the representation of a type reference in the schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpTypeReference {
+    /// subject_definition_name is the name of the subject's definition.
+    #[prost(string, tag = "1")]
+    pub subject_definition_name: ::prost::alloc::string::String,
+    /// optional_caveat_name is the name of the caveat that is applied to the subject, if any.
+    #[prost(string, tag = "2")]
+    pub optional_caveat_name: ::prost::alloc::string::String,
+    #[prost(oneof = "exp_type_reference::Typeref", tags = "3, 4, 5")]
+    pub typeref: ::core::option::Option<exp_type_reference::Typeref>,
+}
+/// Nested message and enum types in `ExpTypeReference`.
+pub mod exp_type_reference {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Typeref {
+        /// is_terminal_subject is true if the subject is terminal, meaning it is referenced directly vs a sub-relation.
+        #[prost(bool, tag = "3")]
+        IsTerminalSubject(bool),
+        /// optional_relation_name is the name of the relation that is applied to the subject, if any.
+        #[prost(string, tag = "4")]
+        OptionalRelationName(::prost::alloc::string::String),
+        /// is_public_wildcard is true if the subject is a public wildcard.
+        #[prost(bool, tag = "5")]
+        IsPublicWildcard(bool),
+    }
+}
+/// ExpPermission is the representation of a permission in the schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpPermission {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// comment is a human-readable comments on the permission. Will include
+    /// delimiter characters.
+    #[prost(string, tag = "2")]
+    pub comment: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub parent_definition_name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalComputablePermissionsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    #[prost(string, tag = "2")]
+    pub definition_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub relation_name: ::prost::alloc::string::String,
+    /// optional_definition_name_match is a prefix that is matched against the definition name(s)
+    /// for the permissions returned.
+    /// If not specified, will be ignored.
+    #[prost(string, tag = "4")]
+    pub optional_definition_name_filter: ::prost::alloc::string::String,
+}
+/// ExpRelationReference is a reference to a relation or permission in the schema.
+#[derive(Eq, Hash)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpRelationReference {
+    #[prost(string, tag = "1")]
+    pub definition_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub relation_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_permission: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalComputablePermissionsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub permissions: ::prost::alloc::vec::Vec<ExpRelationReference>,
+    /// read_at is the ZedToken at which the schema was read.
+    #[prost(message, optional, tag = "2")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalDependentRelationsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    #[prost(string, tag = "2")]
+    pub definition_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub permission_name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalDependentRelationsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub relations: ::prost::alloc::vec::Vec<ExpRelationReference>,
+    /// read_at is the ZedToken at which the schema was read.
+    #[prost(message, optional, tag = "2")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalDiffSchemaRequest {
+    #[prost(message, optional, tag = "1")]
+    pub consistency: ::core::option::Option<Consistency>,
+    #[prost(string, tag = "2")]
+    pub comparison_schema: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExperimentalDiffSchemaResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub diffs: ::prost::alloc::vec::Vec<ExpSchemaDiff>,
+    /// read_at is the ZedToken at which the schema was read.
+    #[prost(message, optional, tag = "2")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpRelationSubjectTypeChange {
+    #[prost(message, optional, tag = "1")]
+    pub relation: ::core::option::Option<ExpRelation>,
+    #[prost(message, optional, tag = "2")]
+    pub changed_subject_type: ::core::option::Option<ExpTypeReference>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpCaveatParameterTypeChange {
+    #[prost(message, optional, tag = "1")]
+    pub parameter: ::core::option::Option<ExpCaveatParameter>,
+    #[prost(string, tag = "2")]
+    pub previous_type: ::prost::alloc::string::String,
+}
+/// ExpSchemaDiff is the representation of a diff between two schemas.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExpSchemaDiff {
+    #[prost(
+        oneof = "exp_schema_diff::Diff",
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
+    )]
+    pub diff: ::core::option::Option<exp_schema_diff::Diff>,
+}
+/// Nested message and enum types in `ExpSchemaDiff`.
+pub mod exp_schema_diff {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Diff {
+        #[prost(message, tag = "1")]
+        DefinitionAdded(super::ExpDefinition),
+        #[prost(message, tag = "2")]
+        DefinitionRemoved(super::ExpDefinition),
+        #[prost(message, tag = "3")]
+        DefinitionDocCommentChanged(super::ExpDefinition),
+        #[prost(message, tag = "4")]
+        RelationAdded(super::ExpRelation),
+        #[prost(message, tag = "5")]
+        RelationRemoved(super::ExpRelation),
+        #[prost(message, tag = "6")]
+        RelationDocCommentChanged(super::ExpRelation),
+        #[prost(message, tag = "7")]
+        RelationSubjectTypeAdded(super::ExpRelationSubjectTypeChange),
+        #[prost(message, tag = "8")]
+        RelationSubjectTypeRemoved(super::ExpRelationSubjectTypeChange),
+        #[prost(message, tag = "9")]
+        PermissionAdded(super::ExpPermission),
+        #[prost(message, tag = "10")]
+        PermissionRemoved(super::ExpPermission),
+        #[prost(message, tag = "11")]
+        PermissionDocCommentChanged(super::ExpPermission),
+        #[prost(message, tag = "12")]
+        PermissionExprChanged(super::ExpPermission),
+        #[prost(message, tag = "13")]
+        CaveatAdded(super::ExpCaveat),
+        #[prost(message, tag = "14")]
+        CaveatRemoved(super::ExpCaveat),
+        #[prost(message, tag = "15")]
+        CaveatDocCommentChanged(super::ExpCaveat),
+        #[prost(message, tag = "16")]
+        CaveatExprChanged(super::ExpCaveat),
+        #[prost(message, tag = "17")]
+        CaveatParameterAdded(super::ExpCaveatParameter),
+        #[prost(message, tag = "18")]
+        CaveatParameterRemoved(super::ExpCaveatParameter),
+        #[prost(message, tag = "19")]
+        CaveatParameterTypeChanged(super::ExpCaveatParameterTypeChange),
+    }
+}
+/// Generated client implementations.
+pub mod experimental_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// ExperimentalService exposes a number of APIs that are currently being
+    /// prototyped and tested for future inclusion in the stable API.
+    #[derive(Debug, Clone)]
+    pub struct ExperimentalServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ExperimentalServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ExperimentalServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ExperimentalServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ExperimentalServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// BulkImportRelationships is a faster path to writing a large number of
+        /// relationships at once. It is both batched and streaming. For maximum
+        /// performance, the caller should attempt to write relationships in as close
+        /// to relationship sort order as possible: (resource.object_type,
+        /// resource.object_id, relation, subject.object.object_type,
+        /// subject.object.object_id, subject.optional_relation)
+        ///
+        /// EXPERIMENTAL
+        /// https://github.com/authzed/spicedb/issues/1303
+        pub async fn bulk_import_relationships(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<
+                Message = super::BulkImportRelationshipsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkImportRelationshipsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/BulkImportRelationships",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "BulkImportRelationships",
+                    ),
+                );
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// BulkExportRelationships is the fastest path available to exporting
+        /// relationships from the server. It is resumable, and will return results
+        /// in an order determined by the server.
+        pub async fn bulk_export_relationships(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkExportRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<
+                tonic::codec::Streaming<super::BulkExportRelationshipsResponse>,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/BulkExportRelationships",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "BulkExportRelationships",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// NOTE: BulkCheckPermission has been promoted to the stable API as "CheckBulkPermission" and the
+        /// API will be removed from experimental in a future release.
+        #[deprecated]
+        pub async fn bulk_check_permission(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkCheckPermissionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkCheckPermissionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/BulkCheckPermission",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "BulkCheckPermission",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: ReflectSchema is an API that allows clients to reflect the schema stored in
+        /// SpiceDB. This is useful for clients that need to introspect the schema of a SpiceDB instance.
+        pub async fn experimental_reflect_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExperimentalReflectSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalReflectSchemaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalReflectSchema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalReflectSchema",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: ComputablePermissions is an API that allows clients to request the set of
+        /// permissions that compute based off a relation. For example, if a schema has a relation
+        /// `viewer` and a permission `view` defined as `permission view = viewer + editor`, then the
+        /// computable permissions for the relation `viewer` will include `view`.
+        pub async fn experimental_computable_permissions(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ExperimentalComputablePermissionsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalComputablePermissionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalComputablePermissions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalComputablePermissions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: DependentRelations is an API that allows clients to request the set of
+        /// relations and permissions that used to compute a permission, recursively. It is the
+        /// inverse of the ComputablePermissions API.
+        pub async fn experimental_dependent_relations(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ExperimentalDependentRelationsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalDependentRelationsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalDependentRelations",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalDependentRelations",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: DiffSchema is an API that allows clients to request the difference between the
+        /// specified schema and the schema stored in SpiceDB. This is useful for clients that need to
+        /// introspect the schema of a SpiceDB instance.
+        pub async fn experimental_diff_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExperimentalDiffSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalDiffSchemaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalDiffSchema",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalDiffSchema",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: RegisterRelationshipCounter registers a new filter for counting relationships. A filter must be registered before
+        /// a count can be requested.
+        pub async fn experimental_register_relationship_counter(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ExperimentalRegisterRelationshipCounterRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalRegisterRelationshipCounterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalRegisterRelationshipCounter",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalRegisterRelationshipCounter",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: CountRelationships returns the count of relationships for *pre-registered* filter.
+        pub async fn experimental_count_relationships(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ExperimentalCountRelationshipsRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalCountRelationshipsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalCountRelationships",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalCountRelationships",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// EXPERIMENTAL: UnregisterRelationshipCounter unregisters an existing filter for counting relationships.
+        pub async fn experimental_unregister_relationship_counter(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::ExperimentalUnregisterRelationshipCounterRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalUnregisterRelationshipCounterResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/authzed.api.v1.ExperimentalService/ExperimentalUnregisterRelationshipCounter",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "authzed.api.v1.ExperimentalService",
+                        "ExperimentalUnregisterRelationshipCounter",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod experimental_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ExperimentalServiceServer.
+    #[async_trait]
+    pub trait ExperimentalService: std::marker::Send + std::marker::Sync + 'static {
+        /// BulkImportRelationships is a faster path to writing a large number of
+        /// relationships at once. It is both batched and streaming. For maximum
+        /// performance, the caller should attempt to write relationships in as close
+        /// to relationship sort order as possible: (resource.object_type,
+        /// resource.object_id, relation, subject.object.object_type,
+        /// subject.object.object_id, subject.optional_relation)
+        ///
+        /// EXPERIMENTAL
+        /// https://github.com/authzed/spicedb/issues/1303
+        async fn bulk_import_relationships(
+            &self,
+            request: tonic::Request<
+                tonic::Streaming<super::BulkImportRelationshipsRequest>,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkImportRelationshipsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the BulkExportRelationships method.
+        type BulkExportRelationshipsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::BulkExportRelationshipsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        /// BulkExportRelationships is the fastest path available to exporting
+        /// relationships from the server. It is resumable, and will return results
+        /// in an order determined by the server.
+        async fn bulk_export_relationships(
+            &self,
+            request: tonic::Request<super::BulkExportRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::BulkExportRelationshipsStream>,
+            tonic::Status,
+        >;
+        /// NOTE: BulkCheckPermission has been promoted to the stable API as "CheckBulkPermission" and the
+        /// API will be removed from experimental in a future release.
+        async fn bulk_check_permission(
+            &self,
+            request: tonic::Request<super::BulkCheckPermissionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BulkCheckPermissionResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: ReflectSchema is an API that allows clients to reflect the schema stored in
+        /// SpiceDB. This is useful for clients that need to introspect the schema of a SpiceDB instance.
+        async fn experimental_reflect_schema(
+            &self,
+            request: tonic::Request<super::ExperimentalReflectSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalReflectSchemaResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: ComputablePermissions is an API that allows clients to request the set of
+        /// permissions that compute based off a relation. For example, if a schema has a relation
+        /// `viewer` and a permission `view` defined as `permission view = viewer + editor`, then the
+        /// computable permissions for the relation `viewer` will include `view`.
+        async fn experimental_computable_permissions(
+            &self,
+            request: tonic::Request<super::ExperimentalComputablePermissionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalComputablePermissionsResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: DependentRelations is an API that allows clients to request the set of
+        /// relations and permissions that used to compute a permission, recursively. It is the
+        /// inverse of the ComputablePermissions API.
+        async fn experimental_dependent_relations(
+            &self,
+            request: tonic::Request<super::ExperimentalDependentRelationsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalDependentRelationsResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: DiffSchema is an API that allows clients to request the difference between the
+        /// specified schema and the schema stored in SpiceDB. This is useful for clients that need to
+        /// introspect the schema of a SpiceDB instance.
+        async fn experimental_diff_schema(
+            &self,
+            request: tonic::Request<super::ExperimentalDiffSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalDiffSchemaResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: RegisterRelationshipCounter registers a new filter for counting relationships. A filter must be registered before
+        /// a count can be requested.
+        async fn experimental_register_relationship_counter(
+            &self,
+            request: tonic::Request<
+                super::ExperimentalRegisterRelationshipCounterRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalRegisterRelationshipCounterResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: CountRelationships returns the count of relationships for *pre-registered* filter.
+        async fn experimental_count_relationships(
+            &self,
+            request: tonic::Request<super::ExperimentalCountRelationshipsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalCountRelationshipsResponse>,
+            tonic::Status,
+        >;
+        /// EXPERIMENTAL: UnregisterRelationshipCounter unregisters an existing filter for counting relationships.
+        async fn experimental_unregister_relationship_counter(
+            &self,
+            request: tonic::Request<
+                super::ExperimentalUnregisterRelationshipCounterRequest,
+            >,
+        ) -> std::result::Result<
+            tonic::Response<super::ExperimentalUnregisterRelationshipCounterResponse>,
+            tonic::Status,
+        >;
+    }
+    /// ExperimentalService exposes a number of APIs that are currently being
+    /// prototyped and tested for future inclusion in the stable API.
+    #[derive(Debug)]
+    pub struct ExperimentalServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ExperimentalServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ExperimentalServiceServer<T>
+    where
+        T: ExperimentalService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.v1.ExperimentalService/BulkImportRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkImportRelationshipsSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::ClientStreamingService<
+                        super::BulkImportRelationshipsRequest,
+                    > for BulkImportRelationshipsSvc<T> {
+                        type Response = super::BulkImportRelationshipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                tonic::Streaming<super::BulkImportRelationshipsRequest>,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::bulk_import_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BulkImportRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/BulkExportRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkExportRelationshipsSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::ServerStreamingService<
+                        super::BulkExportRelationshipsRequest,
+                    > for BulkExportRelationshipsSvc<T> {
+                        type Response = super::BulkExportRelationshipsResponse;
+                        type ResponseStream = T::BulkExportRelationshipsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::BulkExportRelationshipsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::bulk_export_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BulkExportRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/BulkCheckPermission" => {
+                    #[allow(non_camel_case_types)]
+                    struct BulkCheckPermissionSvc<T: ExperimentalService>(pub Arc<T>);
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<super::BulkCheckPermissionRequest>
+                    for BulkCheckPermissionSvc<T> {
+                        type Response = super::BulkCheckPermissionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BulkCheckPermissionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::bulk_check_permission(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BulkCheckPermissionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalReflectSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalReflectSchemaSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalReflectSchemaRequest,
+                    > for ExperimentalReflectSchemaSvc<T> {
+                        type Response = super::ExperimentalReflectSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalReflectSchemaRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_reflect_schema(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalReflectSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalComputablePermissions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalComputablePermissionsSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalComputablePermissionsRequest,
+                    > for ExperimentalComputablePermissionsSvc<T> {
+                        type Response = super::ExperimentalComputablePermissionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalComputablePermissionsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_computable_permissions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalComputablePermissionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalDependentRelations" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalDependentRelationsSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalDependentRelationsRequest,
+                    > for ExperimentalDependentRelationsSvc<T> {
+                        type Response = super::ExperimentalDependentRelationsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalDependentRelationsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_dependent_relations(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalDependentRelationsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalDiffSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalDiffSchemaSvc<T: ExperimentalService>(pub Arc<T>);
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<super::ExperimentalDiffSchemaRequest>
+                    for ExperimentalDiffSchemaSvc<T> {
+                        type Response = super::ExperimentalDiffSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExperimentalDiffSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_diff_schema(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalDiffSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalRegisterRelationshipCounter" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalRegisterRelationshipCounterSvc<
+                        T: ExperimentalService,
+                    >(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalRegisterRelationshipCounterRequest,
+                    > for ExperimentalRegisterRelationshipCounterSvc<T> {
+                        type Response = super::ExperimentalRegisterRelationshipCounterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalRegisterRelationshipCounterRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_register_relationship_counter(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalRegisterRelationshipCounterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalCountRelationships" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalCountRelationshipsSvc<T: ExperimentalService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalCountRelationshipsRequest,
+                    > for ExperimentalCountRelationshipsSvc<T> {
+                        type Response = super::ExperimentalCountRelationshipsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalCountRelationshipsRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_count_relationships(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalCountRelationshipsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.ExperimentalService/ExperimentalUnregisterRelationshipCounter" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExperimentalUnregisterRelationshipCounterSvc<
+                        T: ExperimentalService,
+                    >(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: ExperimentalService,
+                    > tonic::server::UnaryService<
+                        super::ExperimentalUnregisterRelationshipCounterRequest,
+                    > for ExperimentalUnregisterRelationshipCounterSvc<T> {
+                        type Response = super::ExperimentalUnregisterRelationshipCounterResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ExperimentalUnregisterRelationshipCounterRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExperimentalService>::experimental_unregister_relationship_counter(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExperimentalUnregisterRelationshipCounterSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for ExperimentalServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.v1.ExperimentalService";
+    impl<T> tonic::server::NamedService for ExperimentalServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
+/// ReadSchemaRequest returns the schema from the database.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReadSchemaRequest {}
+/// ReadSchemaResponse is the resulting data after having read the Object
+/// Definitions from a Schema.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReadSchemaResponse {
+    /// schema_text is the textual form of the current schema in the system
+    #[prost(string, tag = "1")]
+    pub schema_text: ::prost::alloc::string::String,
+    /// read_at is the ZedToken at which the schema was read.
+    #[prost(message, optional, tag = "2")]
+    pub read_at: ::core::option::Option<ZedToken>,
+}
+/// WriteSchemaRequest is the required data used to "upsert" the Schema of a
+/// Permissions System.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriteSchemaRequest {
+    /// The Schema containing one or more Object Definitions that will be written
+    /// to the Permissions System.
+    ///
+    /// 4MiB
+    #[prost(string, tag = "1")]
+    pub schema: ::prost::alloc::string::String,
+}
+/// WriteSchemaResponse is the resulting data after having written a Schema to
+/// a Permissions System.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WriteSchemaResponse {
+    /// written_at is the ZedToken at which the schema was written.
+    #[prost(message, optional, tag = "1")]
+    pub written_at: ::core::option::Option<ZedToken>,
+}
+/// Generated client implementations.
+pub mod schema_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// SchemaService implements operations on a Permissions System's Schema.
+    #[derive(Debug, Clone)]
+    pub struct SchemaServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl SchemaServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> SchemaServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> SchemaServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            SchemaServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// Read returns the current Object Definitions for a Permissions System.
+        ///
+        /// Errors include:
+        /// - INVALID_ARGUMENT: a provided value has failed to semantically validate
+        /// - NOT_FOUND: no schema has been defined
+        pub async fn read_schema(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReadSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReadSchemaResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1700,8 +4483,7 @@ pub mod schema_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1716,6 +4498,246 @@ pub mod schema_service_client {
         }
     }
 }
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod schema_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with SchemaServiceServer.
+    #[async_trait]
+    pub trait SchemaService: std::marker::Send + std::marker::Sync + 'static {
+        /// Read returns the current Object Definitions for a Permissions System.
+        ///
+        /// Errors include:
+        /// - INVALID_ARGUMENT: a provided value has failed to semantically validate
+        /// - NOT_FOUND: no schema has been defined
+        async fn read_schema(
+            &self,
+            request: tonic::Request<super::ReadSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReadSchemaResponse>,
+            tonic::Status,
+        >;
+        /// Write overwrites the current Object Definitions for a Permissions System.
+        async fn write_schema(
+            &self,
+            request: tonic::Request<super::WriteSchemaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WriteSchemaResponse>,
+            tonic::Status,
+        >;
+    }
+    /// SchemaService implements operations on a Permissions System's Schema.
+    #[derive(Debug)]
+    pub struct SchemaServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> SchemaServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SchemaServiceServer<T>
+    where
+        T: SchemaService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.v1.SchemaService/ReadSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReadSchemaSvc<T: SchemaService>(pub Arc<T>);
+                    impl<
+                        T: SchemaService,
+                    > tonic::server::UnaryService<super::ReadSchemaRequest>
+                    for ReadSchemaSvc<T> {
+                        type Response = super::ReadSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReadSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SchemaService>::read_schema(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReadSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/authzed.api.v1.SchemaService/WriteSchema" => {
+                    #[allow(non_camel_case_types)]
+                    struct WriteSchemaSvc<T: SchemaService>(pub Arc<T>);
+                    impl<
+                        T: SchemaService,
+                    > tonic::server::UnaryService<super::WriteSchemaRequest>
+                    for WriteSchemaSvc<T> {
+                        type Response = super::WriteSchemaResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WriteSchemaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SchemaService>::write_schema(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WriteSchemaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for SchemaServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.v1.SchemaService";
+    impl<T> tonic::server::NamedService for SchemaServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
 /// WatchRequest specifies the object definitions for which we want to start
 /// watching mutations, and an optional start snapshot for when to start
 /// watching.
@@ -1758,7 +4780,13 @@ pub struct WatchResponse {
 }
 /// Generated client implementations.
 pub mod watch_service_client {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     #[derive(Debug, Clone)]
@@ -1780,8 +4808,8 @@ pub mod watch_service_client {
     where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
     {
         pub fn new(inner: T) -> Self {
             let inner = tonic::client::Grpc::new(inner);
@@ -1806,7 +4834,7 @@ pub mod watch_service_client {
             >,
             <T as tonic::codegen::Service<
                 http::Request<tonic::body::BoxBody>,
-            >>::Error: Into<StdError> + Send + Sync,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
             WatchServiceClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -1852,8 +4880,7 @@ pub mod watch_service_client {
                 .ready()
                 .await
                 .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
+                    tonic::Status::unknown(
                         format!("Service was not ready: {}", e.into()),
                     )
                 })?;
@@ -1868,3 +4895,188 @@ pub mod watch_service_client {
         }
     }
 }
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod watch_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WatchServiceServer.
+    #[async_trait]
+    pub trait WatchService: std::marker::Send + std::marker::Sync + 'static {
+        /// Server streaming response type for the Watch method.
+        type WatchStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn watch(
+            &self,
+            request: tonic::Request<super::WatchRequest>,
+        ) -> std::result::Result<tonic::Response<Self::WatchStream>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct WatchServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> WatchServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for WatchServiceServer<T>
+    where
+        T: WatchService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/authzed.api.v1.WatchService/Watch" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchSvc<T: WatchService>(pub Arc<T>);
+                    impl<
+                        T: WatchService,
+                    > tonic::server::ServerStreamingService<super::WatchRequest>
+                    for WatchSvc<T> {
+                        type Response = super::WatchResponse;
+                        type ResponseStream = T::WatchStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WatchService>::watch(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
+            }
+        }
+    }
+    impl<T> Clone for WatchServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    /// Generated gRPC service name
+    pub const SERVICE_NAME: &str = "authzed.api.v1.WatchService";
+    impl<T> tonic::server::NamedService for WatchServiceServer<T> {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+}
@@ -2,17 +2,35 @@
 
 pub mod authzed {
     pub mod api {
+        #[cfg(feature = "developer")]
+        pub mod v0 {
+            include!("gen/authzed.api.v0.rs");
+        }
         pub mod v1 {
             include!("gen/authzed.api.v1.rs");
         }
+        #[cfg(feature = "alpha")]
+        pub mod v1alpha1 {
+            include!("gen/authzed.api.v1alpha1.rs");
+        }
+        #[cfg(feature = "materialize")]
+        pub mod materialize {
+            pub mod v0 {
+                include!("gen/authzed.api.materialize.v0.rs");
+            }
+        }
     }
 }
+// Doc comments are copied verbatim from the protos.
+#[allow(clippy::doc_overindented_list_items)]
 pub mod google {
     pub mod rpc {
         include!("gen/google.api.rs");
         include!("gen/google.rpc.rs");
     }
 }
+// `len` is a generated accessor for the `len` rule, not a collection length.
+#[allow(clippy::len_without_is_empty)]
 pub mod validate {
     include!("gen/validate.rs");
 }
@@ -52,6 +70,7 @@ mod test {
             .await
             .unwrap();
 
+        #[allow(clippy::result_large_err)]
         let interceptor = move |mut req: Request<()>| {
             req.metadata_mut()
                 .insert("authorization", preshared_key.clone());
@@ -196,4 +215,60 @@ definition document {
             .into_inner();
         assert!(response.deleted_at.is_some());
     }
+
+    #[cfg(feature = "server")]
+    #[test]
+    pub async fn test_server() {
+        use std::sync::Mutex;
+
+        use tonic::codegen::http::Uri;
+        use tonic::{Response, Status};
+
+        use crate::authzed::api::v1::schema_service_server::{SchemaService, SchemaServiceServer};
+        use crate::authzed::api::v1::{ReadSchemaResponse, WriteSchemaResponse};
+
+        #[derive(Default)]
+        struct Schemas(Mutex<Option<String>>);
+
+        #[tonic::async_trait]
+        impl SchemaService for Schemas {
+            async fn read_schema(
+                &self,
+                _: Request<ReadSchemaRequest>,
+            ) -> Result<Response<ReadSchemaResponse>, Status> {
+                let schema = self.0.lock().unwrap().clone();
+                let schema_text =
+                    schema.ok_or_else(|| Status::not_found("No schema has been defined"))?;
+                Ok(Response::new(ReadSchemaResponse {
+                    schema_text,
+                    read_at: None,
+                }))
+            }
+
+            async fn write_schema(
+                &self,
+                request: Request<WriteSchemaRequest>,
+            ) -> Result<Response<WriteSchemaResponse>, Status> {
+                *self.0.lock().unwrap() = Some(request.into_inner().schema);
+                Ok(Response::new(WriteSchemaResponse { written_at: None }))
+            }
+        }
+
+        // Serve the fake in-process, without a network connection.
+        let server = SchemaServiceServer::new(Schemas::default());
+        let mut schemas = SchemaServiceClient::with_origin(server, Uri::from_static("http://fake"));
+
+        let err = schemas.read_schema(ReadSchemaRequest {}).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+
+        let schema = "definition user {}";
+        schemas
+            .write_schema(WriteSchemaRequest {
+                schema: schema.to_string(),
+            })
+            .await
+            .unwrap();
+        let response = schemas.read_schema(ReadSchemaRequest {}).await.unwrap();
+        assert_eq!(response.into_inner().schema_text, schema);
+    }
 }